@@ -0,0 +1,25 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dataview::DataView;
+
+static DATA: [u64; 64] = [0x0123456789abcdef; 64];
+
+fn read(c: &mut Criterion) {
+	let view = DataView::from(&DATA);
+	c.bench_function("read u32", |b| b.iter(|| view.try_read::<u32>(black_box(13))));
+	c.bench_function("read [u8; 64]", |b| b.iter(|| view.try_read::<[u8; 64]>(black_box(13))));
+}
+
+fn get(c: &mut Criterion) {
+	let view = DataView::from(&DATA);
+	c.bench_function("get u32", |b| b.iter(|| view.try_get::<u32>(black_box(12)).copied()));
+	c.bench_function("get misaligned u32", |b| b.iter(|| view.try_get::<u32>(black_box(13)).copied()));
+}
+
+fn slice(c: &mut Criterion) {
+	let view = DataView::from(&DATA);
+	c.bench_function("slice u32", |b| b.iter(|| view.try_slice::<u32>(black_box(16), black_box(32)).map(|s| s.len())));
+	c.bench_function("slice u32 out of bounds", |b| b.iter(|| view.try_slice::<u32>(black_box(16), black_box(usize::MAX / 2)).map(|s| s.len())));
+}
+
+criterion_group!(benches, read, get, slice);
+criterion_main!(benches);
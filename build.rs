@@ -0,0 +1,19 @@
+use std::{env, process::Command};
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rustc-check-cfg=cfg(dataview_no_offset_of)");
+
+	// core::mem::offset_of! is stable since Rust 1.77
+	if matches!(rustc_minor_version(), Some(minor) if minor < 77) {
+		println!("cargo:rustc-cfg=dataview_no_offset_of");
+	}
+}
+
+fn rustc_minor_version() -> Option<u32> {
+	let rustc = env::var_os("RUSTC")?;
+	let output = Command::new(rustc).arg("--version").output().ok()?;
+	let version = String::from_utf8(output.stdout).ok()?;
+	// rustc 1.77.0 (aedd173a2 2024-03-17)
+	version.split('.').nth(1)?.parse().ok()
+}
@@ -0,0 +1,135 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, Member, Result};
+
+use crate::pod::{offset_of, Endian, FieldAttrs, PodAttrs};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+	let attrs = PodAttrs::parse(&input.attrs)?;
+	let name = &input.ident;
+	let krate = &attrs.krate;
+
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(fields) => &fields.named,
+			_ => return Err(Error::new(name.span(), format!("cannot derive `FieldOffsets` for type `{}`: only structs with named fields are supported", name))),
+		},
+		_ => return Err(Error::new(name.span(), format!("cannot derive `FieldOffsets` for type `{}`: only structs with named fields are supported", name))),
+	};
+
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+	let ty = quote!(#name #ty_generics);
+	let is_generic = !input.generics.params.is_empty();
+
+	let vis = &input.vis;
+	let offsets_name = format_ident!("{}FieldOffsets", name);
+	let offsets_doc = format!("Offsets of the fields of [`{}`].", name);
+	let typed_name = format_ident!("{}TypedOffsets", name);
+	let typed_doc = format!("Typed offsets of the fields of [`{}`].", name);
+	let spans_name = format_ident!("{}FieldSpans", name);
+	let spans_doc = format!("Byte ranges of the fields of [`{}`].", name);
+	let accessors: Vec<_> = fields.iter().map(|field| format_ident!("offset_of_{}", field.ident.as_ref().unwrap().to_string().trim_start_matches("r#"))).collect();
+	let accessor_docs: Vec<_> = fields.iter().map(|field| format!("Returns the offset of the `{}` field.", field.ident.as_ref().unwrap())).collect();
+	let offsets_field_docs: Vec<_> = fields.iter().map(|field| format!("Offset of the `{}` field.", field.ident.as_ref().unwrap())).collect();
+	let spans_field_docs: Vec<_> = fields.iter().map(|field| format!("Byte range of the `{}` field.", field.ident.as_ref().unwrap())).collect();
+	let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+	let field_names: Vec<_> = fields.iter().map(|field| field.ident.as_ref().unwrap()).collect();
+	let field_tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+	let offsets: Vec<_> = field_names.iter().map(|&ident| offset_of(&ty, &Member::Named(ident.clone()))).collect();
+
+	// Nested fields use the field offsets of their type shifted by the offset of the field
+	let mut offset_tys = Vec::new();
+	let mut offset_values = Vec::new();
+	for (field, offset) in fields.iter().zip(&offsets) {
+		let ty = &field.ty;
+		if FieldAttrs::parse(&field.attrs, Endian::Native)?.nested {
+			if is_generic {
+				return Err(Error::new_spanned(field, "nested field offsets are not supported in generic structs"));
+			}
+			offset_tys.push(quote!(<#ty as #krate::__FieldOffsets>::FieldOffsets));
+			offset_values.push(quote!(<#ty>::__field_offsets_at(base + #offset)));
+		}
+		else {
+			offset_tys.push(quote!(usize));
+			offset_values.push(quote!(base + #offset));
+		}
+	}
+
+	let reflection = if attrs.fields {
+		let names = field_names.iter().map(|ident| ident.to_string().trim_start_matches("r#").to_string());
+		quote! {
+			/// Table of the name, offset and size of every field.
+			#vis const FIELDS: &'static [#krate::FieldInfo] = &[
+				#(#krate::FieldInfo {
+					name: #names,
+					offset: #offsets,
+					size: ::core::mem::size_of::<#field_tys>(),
+				},)*
+			];
+			/// Looks up a field by name.
+			#vis fn field_by_name(name: &str) -> Option<&'static #krate::FieldInfo> {
+				#krate::FieldInfo::find(Self::FIELDS, name)
+			}
+		}
+	}
+	else {
+		TokenStream::new()
+	};
+
+	Ok(quote! {
+		#[doc = #offsets_doc]
+		#[derive(Copy, Clone, Debug)]
+		#vis struct #offsets_name {
+			#(
+				#[doc = #offsets_field_docs]
+				#field_vis #field_names: #offset_tys,
+			)*
+		}
+		#[doc = #typed_doc]
+		#[allow(dead_code)]
+		#vis struct #typed_name #impl_generics #where_clause {
+			#(
+				#[doc = #offsets_field_docs]
+				#field_vis #field_names: #krate::Field<#field_tys>,
+			)*
+		}
+		#[doc = #spans_doc]
+		#[derive(Clone, Debug)]
+		#vis struct #spans_name {
+			#(
+				#[doc = #spans_field_docs]
+				#field_vis #field_names: ::core::ops::Range<usize>,
+			)*
+		}
+		const _: () = {
+			impl #impl_generics #krate::__FieldOffsets for #ty #where_clause {
+				type FieldOffsets = #offsets_name;
+			}
+			#[allow(dead_code)]
+			impl #impl_generics #ty where Self: #krate::Pod, #predicates {
+				#vis const FIELD_OFFSETS: #offsets_name = Self::__field_offsets_at(0);
+				#vis const TYPED_OFFSETS: #typed_name #ty_generics = #typed_name {
+					#(#field_names: #krate::Field::new(#offsets),)*
+				};
+				#vis const FIELD_SPANS: #spans_name = #spans_name {
+					#(#field_names: #offsets..#offsets + ::core::mem::size_of::<#field_tys>(),)*
+				};
+				#(
+					#[doc = #accessor_docs]
+					#[inline]
+					#field_vis const fn #accessors() -> usize {
+						#offsets
+					}
+				)*
+				#[doc(hidden)]
+				#vis const fn __field_offsets_at(base: usize) -> #offsets_name {
+					#offsets_name {
+						#(#field_names: #offset_values,)*
+					}
+				}
+				#reflection
+			}
+		};
+	})
+}
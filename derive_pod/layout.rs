@@ -0,0 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Member, Result};
+
+use crate::pod::{offset_of, PodAttrs};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+	let attrs = PodAttrs::parse(&input.attrs)?;
+	let name = &input.ident;
+	let krate = &attrs.krate;
+
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => return Err(Error::new(name.span(), format!("cannot derive `Layout` for type `{}`: only structs are supported", name))),
+	};
+
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let ty = quote!(#name #ty_generics);
+
+	let infos = fields.iter().enumerate().map(|(index, field)| {
+		let (member, name) = match &field.ident {
+			Some(ident) => (Member::Named(ident.clone()), ident.to_string().trim_start_matches("r#").to_string()),
+			None => (Member::Unnamed(index.into()), index.to_string()),
+		};
+		let offset = offset_of(&ty, &member);
+		let field_ty = &field.ty;
+		quote! {
+			#krate::FieldInfo {
+				name: #name,
+				offset: #offset,
+				size: ::core::mem::size_of::<#field_ty>(),
+			}
+		}
+	});
+
+	Ok(quote! {
+		impl #impl_generics #krate::Layout for #ty #where_clause {
+			const FIELDS: &'static [#krate::FieldInfo] = &[#(#infos,)*];
+		}
+	})
+}
@@ -4,8 +4,17 @@ Auto derive the `Pod` trait.
 This crate should not be used directly, instead depend on the `dataview` crate with the `derive_pod` feature enabled.
 */
 
+#![allow(clippy::tabs_in_doc_comments)]
+
 use proc_macro::*;
 
+mod field_offsets;
+mod layout;
+mod pod;
+mod try_pod;
+mod view;
+mod write_fields;
+
 /// Derive macro for the `Pod` trait.
 ///
 /// The type is checked for requirements of the `Pod` trait:
@@ -17,27 +26,89 @@ use proc_macro::*;
 ///
 /// Note that it is legal for pod types to be a [ZST](https://doc.rust-lang.org/nomicon/exotic-sizes.html#zero-sized-types-zsts).
 ///
+/// Structs with type parameters are supported if every field has the same type, such as `struct Vec3<T> { x: T, y: T, z: T }`.
+/// The padding of generic structs cannot be checked, but structs whose fields all have the same type never have padding.
+/// This restriction does not apply to transparent generic structs or to generic structs packed with `packed` or `packed(1)`.
+/// Generic structs with `#[repr(align(N))]` are not supported as their trailing padding depends on the instantiation.
+/// The impl requires every type parameter to implement `Pod`.
+///
+/// Structs packed with `#[repr(C, packed)]` never have padding, structs packed with `packed(N)` are checked for padding like any other struct.
+/// Because references to their fields may be unaligned, structs with named fields get a by-value accessor method named after every field.
+///
+/// Unions are supported if every field implements `Pod` and has the same size as the union itself.
+///
+/// # Layout assertions
+///
+/// The expected size and alignment of the type can be asserted with `#[pod(size = N, align = N)]`, both are optional.
+/// This catches layout drift at the definition, eg. when the type mirrors a struct from a C header.
+/// Layout assertions are not supported on generic types.
+///
+/// ```ignore
+/// #[derive(Pod)]
+/// #[pod(size = 8, align = 4)]
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: u32,
+/// 	len: u32,
+/// }
+/// ```
+///
+/// # Crate path
+///
+/// The generated code refers to the `dataview` crate as `::dataview`.
+/// Crates which rename or re-export `dataview` can specify its path with `#[pod(crate = "path")]`.
+/// This attribute is accepted by all the derive macros in this crate.
+///
+/// ```ignore
+/// #[derive(Pod)]
+/// #[pod(crate = "my_crate::dataview")]
+/// #[repr(C)]
+/// struct MyType {
+/// 	field: i32,
+/// }
+/// ```
+///
 /// # Compile errors
 ///
-/// Error reporting is not very ergonomic due to how errors are detected:
+/// Errors are reported at the offending field where possible:
 ///
 /// * `error[E0277]: the trait bound $TYPE: Pod is not satisfied`
 ///
 ///   The struct contains a field whose type does not implement `Pod`.
 ///
-/// * `error[E0512]: cannot transmute between types of different sizes, or dependently-sized types`
+/// * `error[E0308]: mismatched types`, `expected struct padding_before_field<N>, found struct padding_before_field<M>`
 ///
-///   This error means your struct has padding as its size is not equal to a byte array of length equal to the sum of the size of its fields.
+///   The struct has padding before the field: the previous field ends at offset `N` but the field starts at offset `M`.
+///   Insert a padding field of `M - N` bytes before the field.
+///
+/// * `error[E0308]: mismatched types`, `expected struct trailing_padding<N>, found struct trailing_padding<M>`
+///
+///   The struct has trailing padding: the last field ends at offset `N` but the size of the struct is `M`.
+///
+/// * `error[E0308]: mismatched types`, `expected struct size_of_field<N>, found struct size_of_field<M>`
+///
+///   The union has a field of `M` bytes which is smaller than the union of `N` bytes.
+///
+/// * `error[E0308]: mismatched types`, `expected struct expected_size<N>, found struct expected_size<M>`
+///
+///   The size of the type is `M` but `#[pod(size = N)]` was asserted, likewise for `expected_align`.
+///
+/// * `error[E0308]: mismatched types` in `fields_must_have_the_same_type`
+///
+///   The struct has type parameters and its fields do not all have the same type.
 ///
 /// * `error: cannot implement Pod for type $TYPE`
 ///
 ///   Deriving `Pod` is not supported for this type.
 ///
-///   This includes enums, unions and structs with generics or lifetimes.
-#[proc_macro_derive(Pod)]
+///   This includes enums, generic unions, generic structs with `#[repr(align(N))]` and structs with lifetimes or const generics.
+#[proc_macro_derive(Pod, attributes(pod))]
 pub fn pod_derive(input: TokenStream) -> TokenStream {
-	let invoke: TokenStream = "::dataview::derive_pod!".parse().unwrap();
-	invoke.into_iter().chain(Some(TokenTree::Group(Group::new(Delimiter::Brace, input)))).collect()
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	match pod::derive(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
 }
 
 /// Derive macro calculates field offsets.
@@ -46,9 +117,122 @@ pub fn pod_derive(input: TokenStream) -> TokenStream {
 ///
 /// The derive macro adds an associated constant `FIELD_OFFSETS` to the type.
 /// `FIELD_OFFSETS` is an instance of a struct with `usize` fields for every field in the type.
+/// This struct is named after the type with a `FieldOffsets` suffix, eg. `FooFieldOffsets`, and has the same visibility as the type.
 /// The value of each field is the offset of that field in the type.
-#[proc_macro_derive(FieldOffsets)]
+///
+/// The derive macro also adds an associated constant `FIELD_SPANS`, similar to `FIELD_OFFSETS` but its fields are the `start..end` byte ranges of every field in the type.
+/// Its struct is named after the type with a `FieldSpans` suffix, eg. `FooFieldSpans`.
+/// These ranges can be used to index a `DataView`.
+///
+/// The derive macro also adds an associated constant `TYPED_OFFSETS` whose fields are the offsets as `Field<T>`, where `T` is the type of the field.
+/// Its struct is named after the type with a `TypedOffsets` suffix, eg. `FooTypedOffsets`.
+/// Typed offsets can be used to access the fields in a `DataView` without restating their type.
+///
+/// For every field the derive macro also adds a const fn `offset_of_{field}` returning its offset.
+/// These can be called in constant expressions, eg. `[u8; Foo::offset_of_bar()]`.
+///
+/// For generic structs the offsets are computed for every instantiation, eg. `Vec3::<f32>::FIELD_OFFSETS`.
+///
+/// Fields with the `#[pod(nested)]` attribute must have a type which also derives `FieldOffsets`.
+/// Their value in `FIELD_OFFSETS` is the field offsets struct of that type, with offsets relative to the outer type.
+/// Eg. `Outer::FIELD_OFFSETS.inner.x` is the offset of `x` in the `inner` field from the start of `Outer`.
+///
+/// With the `#[pod(fields)]` attribute the derive macro also adds an associated constant `FIELDS`,
+/// a table of `FieldInfo` with the name, offset and size of every field, and an associated function `field_by_name` to look up a field by its name.
+#[proc_macro_derive(FieldOffsets, attributes(pod))]
 pub fn field_offsets(input: TokenStream) -> TokenStream {
-	let invoke: TokenStream = "::dataview::__field_offsets!".parse().unwrap();
-	invoke.into_iter().chain(Some(TokenTree::Group(Group::new(Delimiter::Brace, input)))).collect()
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	match field_offsets::derive(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+/// Derive macro for the `TryPod` trait on fieldless enums.
+///
+/// The enum must be annotated with a primitive integer representation such as `#[repr(u8)]`.
+/// The integer representation cannot be combined with `C`, `align(N)` or `packed` as these change the layout of the enum.
+/// The bits are valid if they are equal to the discriminant of one of the variants.
+#[proc_macro_derive(TryPod, attributes(pod))]
+pub fn try_pod_derive(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	match try_pod::derive(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+/// Derive macro for the `WriteFields` trait.
+///
+/// The type must be a struct, every field's type must implement `AsBytes`.
+/// Unlike `Pod` the struct may contain padding.
+#[proc_macro_derive(WriteFields, attributes(pod))]
+pub fn write_fields_derive(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	match write_fields::derive(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+/// Derive macro for a typed view over the fields of a struct.
+///
+/// For a struct `Foo` generates the types `FooView<'a>` wrapping a `&'a DataView` and `FooViewMut<'a>` wrapping a `&'a mut DataView`.
+/// Their constructors check that the view is large enough to hold the struct.
+/// Every field gets a getter method named after the field and the mutable view also gets a setter method named `set_field`.
+/// The fields are read and written unaligned at their offset in the struct without materializing the struct itself.
+///
+/// The struct must have named fields and be annotated with `#[repr(C)]` or `#[repr(transparent)]`.
+/// The struct does not need to implement `Pod`, but its fields' types must be readable and writable in a `DataView`.
+///
+/// The byte order of the fields can be specified with `#[pod(endian = "be")]`, `"le"` or `"ne"` for native (the default).
+/// The attribute on the struct sets the default for all fields and the attribute on a field overrides it.
+/// The accessors of fields which are not native endian convert to and from native byte order,
+/// these fields must be primitive integer or floating point types.
+///
+/// ```ignore
+/// #[derive(PodView)]
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: u32,
+/// 	len: u16,
+/// }
+///
+/// let mut bytes = [0u8; 6];
+/// let mut header = HeaderViewMut::new(DataView::from_mut(&mut bytes)).unwrap();
+/// header.set_len(42);
+/// assert_eq!(header.len(), 42);
+///
+/// #[derive(PodView)]
+/// #[pod(endian = "be")]
+/// #[repr(C)]
+/// struct UdpHeader {
+/// 	src_port: u16,
+/// 	dst_port: u16,
+/// 	len: u16,
+/// 	#[pod(endian = "ne")]
+/// 	checksum: [u8; 2],
+/// }
+/// ```
+#[proc_macro_derive(PodView, attributes(pod))]
+pub fn pod_view_derive(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	match view::derive(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+/// Derive macro for the `Layout` trait.
+///
+/// The type must be a struct, its fields are described in declaration order.
+/// Tuple struct fields are named by their index.
+/// Unlike `FieldOffsets` the type does not need to implement `Pod`.
+#[proc_macro_derive(Layout, attributes(pod))]
+pub fn layout_derive(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	match layout::derive(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
 }
@@ -0,0 +1,382 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::{parse_quote, Attribute, Data, DeriveInput, Error, Expr, Fields, GenericParam, Ident, Member, Path, Result, Type};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+	let attrs = PodAttrs::parse(&input.attrs)?;
+	let repr = Repr::parse(&input.attrs)?;
+	let name = &input.ident;
+	let krate = &attrs.krate;
+
+	if !repr.c && !repr.transparent {
+		return Err(Error::new(name.span(), "missing repr: `Pod` structs must be annotated with `#[repr(C)]` or `#[repr(transparent)]`"));
+	}
+	for param in &input.generics.params {
+		match param {
+			GenericParam::Type(_) => (),
+			_ => return Err(Error::new_spanned(param, format!("cannot implement `Pod` for type `{}`: lifetimes and const generics are not allowed", name))),
+		}
+	}
+	let is_generic = !input.generics.params.is_empty();
+	if is_generic {
+		if let Some(expr) = attrs.size.as_ref().or(attrs.align.as_ref()) {
+			return Err(Error::new_spanned(expr, "layout assertions are not supported on generic types"));
+		}
+		// Over-aligned structs have trailing padding depending on the instantiation
+		if repr.align {
+			return Err(Error::new_spanned(&input.generics, format!("cannot implement `Pod` for type `{}`: generic structs with `#[repr(align(N))]` are not allowed", name)));
+		}
+	}
+	let layout_check = layout_check(name, &attrs);
+
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		Data::Union(data) => {
+			if is_generic {
+				return Err(Error::new_spanned(&input.generics, format!("cannot implement `Pod` for type `{}`: generic unions are not allowed", name)));
+			}
+			let impl_pod = impl_pod(input, krate, data.fields.named.iter().map(|field| &field.ty));
+			let size_checks = data.fields.named.iter().map(|field| union_size_check(name, field.ident.as_ref().unwrap(), &field.ty));
+			return Ok(quote! {
+				#impl_pod
+				const _: () = {
+					#(#size_checks)*
+				};
+				#layout_check
+			});
+		},
+		Data::Enum(data) => {
+			return Err(Error::new(data.enum_token.span, format!("cannot implement `Pod` for type `{}`: enums are not allowed", name)));
+		},
+	};
+
+	let mut tokens = impl_pod(input, krate, fields.iter().map(|field| &field.ty));
+	tokens.extend(layout_check);
+
+	// Structs packed to 1 byte and transparent structs never have padding
+	if is_generic && repr.packed != Some(1) && !repr.transparent {
+		tokens.extend(same_type_check(input, fields));
+	}
+	else if !is_generic {
+		tokens.extend(padding_check(name, fields));
+	}
+
+	if repr.packed.is_some() {
+		tokens.extend(packed_accessors(input, fields));
+	}
+
+	Ok(tokens)
+}
+
+// Attributes of the form `#[pod(...)]`
+pub struct PodAttrs {
+	pub krate: Path,
+	size: Option<Expr>,
+	align: Option<Expr>,
+	pub endian: Endian,
+	pub fields: bool,
+}
+
+impl PodAttrs {
+	pub fn parse(attrs: &[Attribute]) -> Result<PodAttrs> {
+		let mut result = PodAttrs {
+			krate: parse_quote!(::dataview),
+			size: None,
+			align: None,
+			endian: Endian::Native,
+			fields: false,
+		};
+		for attr in attrs {
+			if !attr.path().is_ident("pod") {
+				continue;
+			}
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("crate") {
+					let lit: syn::LitStr = meta.value()?.parse()?;
+					result.krate = lit.parse()?;
+					Ok(())
+				}
+				else if meta.path.is_ident("size") {
+					result.size = Some(meta.value()?.parse()?);
+					Ok(())
+				}
+				else if meta.path.is_ident("align") {
+					result.align = Some(meta.value()?.parse()?);
+					Ok(())
+				}
+				else if meta.path.is_ident("fields") {
+					result.fields = true;
+					Ok(())
+				}
+				else if meta.path.is_ident("endian") {
+					result.endian = Endian::parse(&meta.value()?.parse()?)?;
+					Ok(())
+				}
+				else {
+					Err(meta.error("unknown pod attribute"))
+				}
+			})?;
+		}
+		Ok(result)
+	}
+}
+
+// Byte order of a field as given by `#[pod(endian = "be")]`
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Endian {
+	Native,
+	Little,
+	Big,
+}
+
+impl Endian {
+	fn parse(lit: &syn::LitStr) -> Result<Endian> {
+		match &*lit.value() {
+			"ne" | "native" => Ok(Endian::Native),
+			"le" | "little" => Ok(Endian::Little),
+			"be" | "big" => Ok(Endian::Big),
+			_ => Err(Error::new(lit.span(), "expected `\"le\"`, `\"be\"` or `\"ne\"`")),
+		}
+	}
+}
+
+// Attributes of the form `#[pod(...)]` on fields
+pub struct FieldAttrs {
+	pub endian: Endian,
+	pub nested: bool,
+}
+
+impl FieldAttrs {
+	pub fn parse(attrs: &[Attribute], endian: Endian) -> Result<FieldAttrs> {
+		let mut result = FieldAttrs { endian, nested: false };
+		for attr in attrs {
+			if !attr.path().is_ident("pod") {
+				continue;
+			}
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("endian") {
+					result.endian = Endian::parse(&meta.value()?.parse()?)?;
+					Ok(())
+				}
+				else if meta.path.is_ident("nested") {
+					result.nested = true;
+					Ok(())
+				}
+				else {
+					Err(meta.error("unknown pod field attribute"))
+				}
+			})?;
+		}
+		Ok(result)
+	}
+}
+
+const INT_REPRS: [&str; 12] = ["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+// The relevant parts of the `#[repr(...)]` attributes
+#[derive(Default)]
+pub struct Repr {
+	pub c: bool,
+	pub transparent: bool,
+	// The packing given by `packed(N)`, a bare `packed` is `packed(1)`
+	pub packed: Option<u64>,
+	pub align: bool,
+	// The primitive integer repr of enums such as `u8`
+	pub int: Option<Ident>,
+}
+
+impl Repr {
+	pub fn parse(attrs: &[Attribute]) -> Result<Repr> {
+		let mut repr = Repr::default();
+		for attr in attrs {
+			if !attr.path().is_ident("repr") {
+				continue;
+			}
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("C") {
+					repr.c = true;
+				}
+				else if meta.path.is_ident("transparent") {
+					repr.transparent = true;
+				}
+				else if meta.path.is_ident("packed") {
+					let mut packing = 1;
+					if meta.input.peek(syn::token::Paren) {
+						let content;
+						syn::parenthesized!(content in meta.input);
+						packing = content.parse::<syn::LitInt>()?.base10_parse()?;
+					}
+					repr.packed = Some(packing);
+				}
+				else if meta.path.is_ident("align") {
+					repr.align = true;
+				}
+				else if let Some(ident) = meta.path.get_ident().filter(|ident| INT_REPRS.iter().any(|int| ident == int)) {
+					repr.int = Some(ident.clone());
+				}
+				// Skip the arguments of `align(N)`
+				if meta.input.peek(syn::token::Paren) {
+					let content;
+					syn::parenthesized!(content in meta.input);
+					let _: TokenStream = content.parse()?;
+				}
+				Ok(())
+			})?;
+		}
+		Ok(repr)
+	}
+}
+
+fn impl_pod<'a>(input: &DeriveInput, krate: &Path, field_tys: impl Iterator<Item = &'a Type>) -> TokenStream {
+	let name = &input.ident;
+	let mut generics = input.generics.clone();
+	for param in generics.type_params_mut() {
+		param.bounds.push(parse_quote!(#krate::Pod));
+	}
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+	let field_bounds = field_tys.map(|ty| quote_spanned!(ty.span()=> #ty: #krate::Pod));
+	quote! {
+		unsafe impl #impl_generics #krate::Pod for #name #ty_generics
+			where Self: 'static, #(#field_bounds,)* #predicates {}
+	}
+}
+
+fn members(fields: &Fields) -> impl Iterator<Item = (Member, &Type, Span)> {
+	fields.iter().enumerate().map(|(index, field)| {
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(index.into()),
+		};
+		(member, &field.ty, field.span())
+	})
+}
+
+fn member_name(member: &Member) -> String {
+	match member {
+		Member::Named(ident) => ident.to_string().trim_start_matches("r#").into(),
+		Member::Unnamed(index) => index.index.to_string(),
+	}
+}
+
+// Computes the offset of a field in a const context
+pub fn offset_of(name: &impl ToTokens, member: &Member) -> TokenStream {
+	quote! {{
+		let uninit = ::core::mem::MaybeUninit::<#name>::uninit();
+		let uninit_ptr = uninit.as_ptr();
+		#[allow(unused_unsafe)]
+		unsafe {
+			let field_ptr = ::core::ptr::addr_of!((*uninit_ptr).#member);
+			(field_ptr as *const u8).offset_from(uninit_ptr as *const u8) as usize
+		}
+	}}
+}
+
+// Compares the offset of every field with the end of the previous field
+// Mismatches are reported as a type error: `expected padding_before_field<N>, found padding_before_field<M>`
+fn padding_check(name: &Ident, fields: &Fields) -> TokenStream {
+	let mut end = quote!(0usize);
+	let mut tokens = TokenStream::new();
+	for (member, ty, span) in members(fields) {
+		let marker = format_ident!("padding_before_{}", member_name(&member), span = span);
+		let offset = offset_of(name, &member);
+		tokens.extend(quote_spanned! {span=>
+			const _: () = {
+				#[allow(non_camel_case_types)]
+				struct #marker<const OFFSET: usize>;
+				let _: #marker<{ #end }> = #marker::<{ #offset }>;
+			};
+		});
+		end = quote!((#offset + ::core::mem::size_of::<#ty>()));
+	}
+	tokens.extend(quote_spanned! {name.span()=>
+		const _: () = {
+			#[allow(non_camel_case_types)]
+			struct trailing_padding<const SIZE: usize>;
+			let _: trailing_padding<{ #end }> = trailing_padding::<{ ::core::mem::size_of::<#name>() }>;
+		};
+	});
+	tokens
+}
+
+// Asserts the layout given by `#[pod(size = N, align = N)]`
+// Mismatches are reported as a type error: `expected expected_size<N>, found expected_size<M>`
+fn layout_check(name: &Ident, attrs: &PodAttrs) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	if let Some(size) = &attrs.size {
+		tokens.extend(quote_spanned! {size.span()=>
+			const _: () = {
+				#[allow(non_camel_case_types)]
+				struct expected_size<const SIZE: usize>;
+				let _: expected_size<{ #size }> = expected_size::<{ ::core::mem::size_of::<#name>() }>;
+			};
+		});
+	}
+	if let Some(align) = &attrs.align {
+		tokens.extend(quote_spanned! {align.span()=>
+			const _: () = {
+				#[allow(non_camel_case_types)]
+				struct expected_align<const ALIGN: usize>;
+				let _: expected_align<{ #align }> = expected_align::<{ ::core::mem::align_of::<#name>() }>;
+			};
+		});
+	}
+	tokens
+}
+
+// The padding of a generic struct depends on its instantiation and cannot be checked
+// Instead require every field to have the same type, these structs are laid out like arrays without padding
+fn same_type_check(input: &DeriveInput, fields: &Fields) -> TokenStream {
+	let mut tys = fields.iter().map(|field| &field.ty);
+	let first_ty = match tys.next() {
+		Some(ty) => ty,
+		None => return TokenStream::new(),
+	};
+	let params = input.generics.type_params().map(|param| &param.ident);
+	let checks = tys.map(|ty| quote_spanned!(ty.span()=> let _: &#ty = field;));
+	quote! {
+		const _: () = {
+			#[allow(dead_code)]
+			fn fields_must_have_the_same_type<#(#params),*>(field: &#first_ty) {
+				#(#checks)*
+			}
+		};
+	}
+}
+
+// Every field must cover the whole union, otherwise writing a smaller field leaves uninitialized bytes
+fn union_size_check(name: &Ident, field: &Ident, ty: &Type) -> TokenStream {
+	let marker = format_ident!("size_of_{}", field, span = field.span());
+	quote_spanned! {field.span()=>
+		{
+			#[allow(non_camel_case_types)]
+			struct #marker<const SIZE: usize>;
+			let _: #marker<{ ::core::mem::size_of::<#name>() }> = #marker::<{ ::core::mem::size_of::<#ty>() }>;
+		}
+	}
+}
+
+// References to the fields of packed structs may be unaligned, provide by-value accessors instead
+fn packed_accessors(input: &DeriveInput, fields: &Fields) -> TokenStream {
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let accessors = fields.iter().filter_map(|field| {
+		let vis = &field.vis;
+		let ident = field.ident.as_ref()?;
+		let ty = &field.ty;
+		Some(quote! {
+			/// Reads the field by value.
+			#[inline]
+			#vis fn #ident(&self) -> #ty {
+				unsafe { ::core::ptr::read_unaligned(::core::ptr::addr_of!(self.#ident)) }
+			}
+		})
+	});
+	quote! {
+		#[allow(dead_code)]
+		impl #impl_generics #name #ty_generics #where_clause {
+			#(#accessors)*
+		}
+	}
+}
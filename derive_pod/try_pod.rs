@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+use crate::pod::{PodAttrs, Repr};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+	let attrs = PodAttrs::parse(&input.attrs)?;
+	let repr = Repr::parse(&input.attrs)?;
+	let name = &input.ident;
+	let krate = &attrs.krate;
+
+	let variants = match &input.data {
+		Data::Enum(data) => &data.variants,
+		_ => return Err(Error::new(name.span(), format!("cannot implement `TryPod` for type `{}`: only fieldless enums are supported", name))),
+	};
+	if !input.generics.params.is_empty() {
+		return Err(Error::new_spanned(&input.generics, format!("cannot implement `TryPod` for type `{}`: generics are not allowed", name)));
+	}
+	for variant in variants {
+		if !matches!(variant.fields, Fields::Unit) {
+			return Err(Error::new_spanned(&variant.fields, format!("cannot implement `TryPod` for type `{}`: only fieldless enums are supported", name)));
+		}
+	}
+	let bits = match &repr.int {
+		Some(int) => int,
+		None => return Err(Error::new(name.span(), format!("cannot implement `TryPod` for type `{}`: enums must be annotated with a primitive integer repr such as `#[repr(u8)]`", name))),
+	};
+
+	// These change the size or layout of the enum away from its integer repr
+	if repr.align || repr.packed.is_some() || repr.c {
+		return Err(Error::new(name.span(), format!("cannot implement `TryPod` for type `{}`: the integer repr cannot be combined with `C`, `align` or `packed`", name)));
+	}
+
+	let variants = variants.iter().map(|variant| &variant.ident);
+	Ok(quote! {
+		unsafe impl #krate::TryPod for #name {
+			type Bits = #bits;
+			#[inline]
+			fn is_valid(bits: &#bits) -> bool {
+				false #(|| *bits == #name::#variants as #bits)*
+			}
+		}
+		const _: () = assert!(::core::mem::size_of::<#name>() == ::core::mem::size_of::<#bits>(), "the size of the enum must match its integer repr");
+	})
+}
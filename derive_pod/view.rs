@@ -0,0 +1,126 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, Member, Result};
+
+use crate::pod::{offset_of, Endian, FieldAttrs, PodAttrs, Repr};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+	let attrs = PodAttrs::parse(&input.attrs)?;
+	let repr = Repr::parse(&input.attrs)?;
+	let name = &input.ident;
+	let krate = &attrs.krate;
+
+	if !repr.c && !repr.transparent {
+		return Err(Error::new(name.span(), "missing repr: `PodView` structs must be annotated with `#[repr(C)]` or `#[repr(transparent)]`"));
+	}
+	if !input.generics.params.is_empty() {
+		return Err(Error::new_spanned(&input.generics, format!("cannot derive `PodView` for type `{}`: generics are not allowed", name)));
+	}
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(fields) => &fields.named,
+			_ => return Err(Error::new(name.span(), format!("cannot derive `PodView` for type `{}`: only structs with named fields are supported", name))),
+		},
+		_ => return Err(Error::new(name.span(), format!("cannot derive `PodView` for type `{}`: only structs with named fields are supported", name))),
+	};
+
+	let vis = &input.vis;
+	let view = format_ident!("{}View", name);
+	let view_mut = format_ident!("{}ViewMut", name);
+	let view_doc = format!("Typed view over the fields of [`{}`] in a byte buffer.", name);
+	let view_mut_doc = format!("Typed mutable view over the fields of [`{}`] in a byte buffer.", name);
+
+	let mut getters = TokenStream::new();
+	let mut setters = TokenStream::new();
+	for field in fields {
+		let field_vis = &field.vis;
+		let ident = field.ident.as_ref().unwrap();
+		let ty = &field.ty;
+		let offset = offset_of(name, &Member::Named(ident.clone()));
+		let setter = format_ident!("set_{}", ident);
+		let getter_doc = format!("Reads the `{}` field.", ident);
+		let setter_doc = format!("Writes the `{}` field.", ident);
+		let (read, write) = match FieldAttrs::parse(&field.attrs, attrs.endian)?.endian {
+			Endian::Native => (
+				quote_spanned!(ty.span()=> self.view.read_unchecked::<#ty>(#offset)),
+				quote_spanned!(ty.span()=> self.view.write_unchecked::<#ty>(#offset, &value)),
+			),
+			Endian::Little => (
+				quote_spanned!(ty.span()=> <#ty>::from_le_bytes(self.view.read_unchecked(#offset))),
+				quote_spanned!(ty.span()=> self.view.write_unchecked(#offset, &<#ty>::to_le_bytes(value))),
+			),
+			Endian::Big => (
+				quote_spanned!(ty.span()=> <#ty>::from_be_bytes(self.view.read_unchecked(#offset))),
+				quote_spanned!(ty.span()=> self.view.write_unchecked(#offset, &<#ty>::to_be_bytes(value))),
+			),
+		};
+		getters.extend(quote_spanned! {ty.span()=>
+			#[doc = #getter_doc]
+			#[inline]
+			#field_vis fn #ident(&self) -> #ty {
+				// The constructor checked that the view is large enough for the struct
+				unsafe { #read }
+			}
+		});
+		setters.extend(quote_spanned! {ty.span()=>
+			#[doc = #setter_doc]
+			#[inline]
+			#field_vis fn #setter(&mut self, value: #ty) {
+				// The constructor checked that the view is large enough for the struct
+				unsafe { #write }
+			}
+		});
+	}
+
+	Ok(quote! {
+		#[doc = #view_doc]
+		#[derive(Copy, Clone)]
+		#vis struct #view<'a> {
+			view: &'a #krate::DataView,
+		}
+
+		#[doc = #view_mut_doc]
+		#vis struct #view_mut<'a> {
+			view: &'a mut #krate::DataView,
+		}
+
+		#[allow(dead_code, clippy::len_without_is_empty)]
+		impl<'a> #view<'a> {
+			/// Creates a view if the bytes are large enough to hold the struct.
+			#[inline]
+			#vis fn new(view: &'a #krate::DataView) -> Option<#view<'a>> {
+				let view = view.index(..::core::mem::size_of::<#name>())?;
+				Some(#view { view })
+			}
+			/// Returns the bytes of the struct.
+			#[inline]
+			#vis fn as_data_view(&self) -> &'a #krate::DataView {
+				self.view
+			}
+			#getters
+		}
+
+		#[allow(dead_code, clippy::len_without_is_empty)]
+		impl<'a> #view_mut<'a> {
+			/// Creates a view if the bytes are large enough to hold the struct.
+			#[inline]
+			#vis fn new(view: &'a mut #krate::DataView) -> Option<#view_mut<'a>> {
+				let view = view.index_mut(..::core::mem::size_of::<#name>())?;
+				Some(#view_mut { view })
+			}
+			/// Returns a shared view of the struct.
+			#[inline]
+			#vis fn as_view(&self) -> #view<'_> {
+				#view { view: self.view }
+			}
+			/// Returns the bytes of the struct.
+			#[inline]
+			#vis fn as_data_view_mut(&mut self) -> &mut #krate::DataView {
+				self.view
+			}
+			#getters
+			#setters
+		}
+	})
+}
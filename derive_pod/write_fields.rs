@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Member, Result};
+
+use crate::pod::{offset_of, PodAttrs};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+	let attrs = PodAttrs::parse(&input.attrs)?;
+	let name = &input.ident;
+	let krate = &attrs.krate;
+
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => return Err(Error::new(name.span(), format!("cannot implement `WriteFields` for type `{}`: only structs are supported", name))),
+	};
+
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+	let ty = quote!(#name #ty_generics);
+	let field_bounds = fields.iter().map(|field| {
+		let field_ty = &field.ty;
+		quote_spanned!(field_ty.span()=> #field_ty: #krate::AsBytes)
+	});
+	let writes = fields.iter().enumerate().map(|(index, field)| {
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(index.into()),
+		};
+		let offset = offset_of(&ty, &member);
+		quote!(view.write(#offset, &self.#member);)
+	});
+
+	Ok(quote! {
+		impl #impl_generics #krate::WriteFields for #ty
+			where #(#field_bounds,)* #predicates
+		{
+			#[inline]
+			fn write_fields(&self, view: &mut #krate::DataView) {
+				#(#writes)*
+			}
+		}
+	})
+}
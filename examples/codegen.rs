@@ -0,0 +1,53 @@
+/*!
+Probes for checking the machine code of the fixed size reads and writes.
+
+```text
+cargo rustc --release --example codegen -- --emit asm
+```
+
+Each probe should compile to a bounds check and a single load or store without any calls.
+*/
+
+use dataview::DataView;
+
+#[no_mangle]
+#[inline(never)]
+pub fn probe_try_read_u16(view: &DataView, offset: usize) -> Option<u16> {
+	view.try_read(offset)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn probe_try_read_u32(view: &DataView, offset: usize) -> Option<u32> {
+	view.try_read(offset)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn probe_try_read_u64(view: &DataView, offset: usize) -> Option<u64> {
+	view.try_read(offset)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn probe_try_write_u32(view: &mut DataView, offset: usize, value: u32) -> Option<()> {
+	view.try_write(offset, &value)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn probe_try_write_u64(view: &mut DataView, offset: usize, value: u64) -> Option<()> {
+	view.try_write(offset, &value)
+}
+
+fn main() {
+	let mut bytes = [0u8; 16];
+	let view = DataView::from_mut(&mut bytes);
+	assert_eq!(probe_try_write_u32(view, 2, 0x01020304), Some(()));
+	assert_eq!(probe_try_write_u64(view, 8, 5), Some(()));
+	assert_eq!(probe_try_write_u64(view, 9, 5), None);
+	assert_eq!(probe_try_read_u16(view, 4), Some(u16::from_ne_bytes([2, 1])));
+	assert_eq!(probe_try_read_u32(view, 2), Some(0x01020304));
+	assert_eq!(probe_try_read_u64(view, 8), Some(5));
+	assert_eq!(probe_try_read_u32(view, usize::MAX), None);
+}
@@ -0,0 +1,23 @@
+/*!
+Replaces the invalid offset panic with a user defined function.
+
+```text
+cargo run --example invalid_offset_hook --features invalid_offset_hook
+```
+*/
+
+use dataview::DataView;
+
+// Called by the panicking methods when the offset is out of bounds or misaligned
+#[no_mangle]
+fn __dataview_invalid_offset() -> ! {
+	println!("invalid offset");
+	std::process::exit(0)
+}
+
+fn main() {
+	let view = DataView::from(&[1u8, 2, 3, 4]);
+	println!("{:?}", view.read::<[u8; 2]>(2));
+	let _ = view.read::<u32>(2);
+	unreachable!("the hook does not return");
+}
@@ -0,0 +1,97 @@
+use core::{fmt, ops};
+use super::*;
+
+mod private {
+	pub trait Sealed {}
+}
+
+/// Alignment marker types for [`Aligned`].
+pub trait Alignment: private::Sealed + Copy + 'static {}
+
+macro_rules! alignments {
+	($($name:ident = $align:literal,)*) => {
+		$(
+			#[doc = concat!("Alignment marker of ", stringify!($align), " bytes.")]
+			#[derive(Copy, Clone, Debug, Default)]
+			#[repr(align($align))]
+			pub struct $name;
+			impl private::Sealed for $name {}
+			impl Alignment for $name {}
+		)*
+	};
+}
+
+alignments! {
+	A1 = 1, A2 = 2, A4 = 4, A8 = 8, A16 = 16, A32 = 32, A64 = 64,
+	A128 = 128, A256 = 256, A512 = 512, A1024 = 1024, A2048 = 2048, A4096 = 4096,
+}
+
+/// Wrapper raising the alignment of a value.
+///
+/// ```
+/// use dataview::{Aligned, A16};
+///
+/// let mut buffer: Aligned<A16, [u8; 4096]> = dataview::zeroed();
+/// let view = buffer.as_data_view_mut();
+/// assert_eq!(view.as_ref().as_ptr() as usize % 16, 0);
+/// assert!(view.try_get::<u128>(0).is_some());
+/// ```
+///
+/// The wrapper itself is not [`Pod`] as it has trailing padding when the size of `T` is not a multiple of the alignment.
+/// Create data views of the inner value instead with [`as_data_view`](Aligned::as_data_view).
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct Aligned<A: Alignment, T: ?Sized> {
+	_align: [A; 0],
+	/// The aligned value.
+	pub value: T,
+}
+
+unsafe impl<A: Alignment, T: Zeroable> Zeroable for Aligned<A, T> {}
+unsafe impl<A: Alignment, T: AnyBitPattern> AnyBitPattern for Aligned<A, T> {}
+
+impl<A: Alignment, T> Aligned<A, T> {
+	/// Wraps the value.
+	#[inline]
+	pub const fn new(value: T) -> Aligned<A, T> {
+		Aligned { _align: [], value }
+	}
+	/// Unwraps the value.
+	#[inline]
+	pub fn into_inner(self) -> T {
+		self.value
+	}
+}
+
+impl<A: Alignment, T: ?Sized + Pod> Aligned<A, T> {
+	/// Returns an aligned data view into the value's memory.
+	#[inline]
+	pub fn as_data_view(&self) -> &DataView {
+		DataView::from(&self.value)
+	}
+	/// Returns an aligned mutable data view into the value's memory.
+	#[inline]
+	pub fn as_data_view_mut(&mut self) -> &mut DataView {
+		DataView::from_mut(&mut self.value)
+	}
+}
+
+impl<A: Alignment, T: ?Sized> ops::Deref for Aligned<A, T> {
+	type Target = T;
+	#[inline]
+	fn deref(&self) -> &T {
+		&self.value
+	}
+}
+impl<A: Alignment, T: ?Sized> ops::DerefMut for Aligned<A, T> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.value
+	}
+}
+
+impl<A: Alignment, T: ?Sized + fmt::Debug> fmt::Debug for Aligned<A, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("Aligned").field(&&self.value).finish()
+	}
+}
@@ -0,0 +1,73 @@
+use core::ops;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use super::*;
+
+/// Wrapper generating Pod values from fuzzer input.
+///
+/// The value is read from the next `size_of::<T>()` bytes of the input, missing bytes are zero.
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use dataview::ArbitraryPod;
+///
+/// #[derive(dataview::Pod, Copy, Clone, Debug)]
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: [u8; 2],
+/// 	len: u16,
+/// }
+///
+/// let mut u = Unstructured::new(&[b'H', b'I', 3, 0, 0xff]);
+/// let header = ArbitraryPod::<Header>::arbitrary(&mut u).unwrap().0;
+/// assert_eq!(header.magic, *b"HI");
+/// assert_eq!(header.len, u16::from_ne_bytes([3, 0]));
+/// assert_eq!(u.len(), 1);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct ArbitraryPod<T>(pub T);
+
+unsafe impl<T: Pod> Pod for ArbitraryPod<T> {}
+
+impl<'a, T: Pod> Arbitrary<'a> for ArbitraryPod<T> {
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<ArbitraryPod<T>> {
+		let mut value = zeroed::<T>();
+		u.fill_buffer(bytes_mut(&mut value))?;
+		Ok(ArbitraryPod(value))
+	}
+	#[inline]
+	fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+		(mem::size_of::<T>(), Some(mem::size_of::<T>()))
+	}
+}
+
+impl<T> ops::Deref for ArbitraryPod<T> {
+	type Target = T;
+	#[inline]
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+impl<T> ops::DerefMut for ArbitraryPod<T> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.0
+	}
+}
+
+/// Borrows a data view from the fuzzer input.
+impl<'a> Arbitrary<'a> for &'a DataView {
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<&'a DataView> {
+		<&'a [u8]>::arbitrary(u).map(DataView::from)
+	}
+	#[inline]
+	fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<&'a DataView> {
+		<&'a [u8]>::arbitrary_take_rest(u).map(DataView::from)
+	}
+	#[inline]
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		<&'a [u8]>::size_hint(depth)
+	}
+}
@@ -0,0 +1,153 @@
+use core::{cell::Cell, fmt, ptr, slice};
+use core::marker::PhantomData;
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// Bump allocator handing out typed allocations from a byte buffer.
+///
+/// Allocations are aligned for their type and live as long as the shared borrow of the arena.
+/// Memory is only reclaimed all at once with [`reset`](Arena::reset).
+///
+/// ```
+/// let mut buffer = [0u8; 64];
+/// let arena = dataview::Arena::new(dataview::DataView::from_mut(&mut buffer));
+///
+/// let a = arena.alloc(1u8);
+/// let b = arena.alloc_slice_copy(&[2u32, 3, 4]);
+/// *a += 1;
+/// b[0] = 5;
+/// assert_eq!(*a, 2);
+/// assert_eq!(b, &[5, 3, 4]);
+/// assert!(arena.used() >= 13);
+/// ```
+///
+/// The *Panicking* methods panic with the message `"invalid offset"` when the arena is exhausted, see [`DataView`].
+pub struct Arena<'a> {
+	ptr: *mut u8,
+	len: usize,
+	position: Cell<usize>,
+	marker: PhantomData<&'a mut DataView>,
+}
+
+impl<'a> Arena<'a> {
+	/// Creates an arena allocating from the bytes of the view.
+	#[inline]
+	pub fn new(view: &'a mut DataView) -> Arena<'a> {
+		let bytes = view.as_mut();
+		Arena {
+			ptr: bytes.as_mut_ptr(),
+			len: bytes.len(),
+			position: Cell::new(0),
+			marker: PhantomData,
+		}
+	}
+	/// Returns the number of bytes allocated, including alignment padding.
+	#[inline]
+	pub fn used(&self) -> usize {
+		self.position.get()
+	}
+	/// Returns the number of bytes left in the arena.
+	#[inline]
+	pub fn remaining(&self) -> usize {
+		self.len - self.position.get()
+	}
+	/// Frees all allocations.
+	///
+	/// Requires a unique borrow to guarantee no allocations are still alive.
+	#[inline]
+	pub fn reset(&mut self) {
+		self.position.set(0);
+	}
+	/// Reserves `size` bytes aligned to `align`.
+	#[inline]
+	fn bump(&self, size: usize, align: usize) -> Option<*mut u8> {
+		let position = self.position.get();
+		let addr = (self.ptr as usize).checked_add(position)?;
+		let start = position + (addr.wrapping_neg() & (align - 1));
+		let end = start.checked_add(size)?;
+		if end > self.len {
+			return None;
+		}
+		self.position.set(end);
+		Some(unsafe { self.ptr.add(start) })
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Allocates a single value.
+impl<'a> Arena<'a> {
+	/// Allocates a value in the arena.
+	#[inline]
+	#[allow(clippy::mut_from_ref)]
+	pub fn try_alloc<T: Pod>(&self, value: T) -> Option<&mut T> {
+		let p = self.bump(mem::size_of::<T>(), mem::align_of::<T>())? as *mut T;
+		unsafe {
+			ptr::write(p, value);
+			Some(&mut *p)
+		}
+	}
+	/// Allocates a value in the arena.
+	#[track_caller]
+	#[inline]
+	#[allow(clippy::mut_from_ref)]
+	pub fn alloc<T: Pod>(&self, value: T) -> &mut T {
+		match self.try_alloc(value) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Allocates a slice of values.
+impl<'a> Arena<'a> {
+	/// Allocates a zero-initialized slice in the arena.
+	#[inline]
+	#[allow(clippy::mut_from_ref)]
+	pub fn try_alloc_slice_zeroed<T: Pod>(&self, len: usize) -> Option<&mut [T]> {
+		let size = mem::size_of::<T>().checked_mul(len)?;
+		let p = self.bump(size, mem::align_of::<T>())?;
+		unsafe {
+			ptr::write_bytes(p, 0, size);
+			Some(slice::from_raw_parts_mut(p as *mut T, len))
+		}
+	}
+	/// Allocates a zero-initialized slice in the arena.
+	#[track_caller]
+	#[inline]
+	#[allow(clippy::mut_from_ref)]
+	pub fn alloc_slice_zeroed<T: Pod>(&self, len: usize) -> &mut [T] {
+		match self.try_alloc_slice_zeroed(len) {
+			Some(values) => values,
+			None => invalid_offset(),
+		}
+	}
+	/// Allocates a copy of the slice in the arena.
+	#[inline]
+	#[allow(clippy::mut_from_ref)]
+	pub fn try_alloc_slice_copy<T: Pod>(&self, values: &[T]) -> Option<&mut [T]> {
+		let p = self.bump(mem::size_of_val(values), mem::align_of::<T>())? as *mut T;
+		unsafe {
+			ptr::copy_nonoverlapping(values.as_ptr(), p, values.len());
+			Some(slice::from_raw_parts_mut(p, values.len()))
+		}
+	}
+	/// Allocates a copy of the slice in the arena.
+	#[track_caller]
+	#[inline]
+	#[allow(clippy::mut_from_ref)]
+	pub fn alloc_slice_copy<T: Pod>(&self, values: &[T]) -> &mut [T] {
+		match self.try_alloc_slice_copy(values) {
+			Some(values) => values,
+			None => invalid_offset(),
+		}
+	}
+}
+
+impl<'a> fmt::Debug for Arena<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Arena").field("used", &self.used()).field("len", &self.len).finish()
+	}
+}
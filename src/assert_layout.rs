@@ -0,0 +1,90 @@
+/// Asserts at compiletime that the types implement [`Pod`](crate::Pod).
+///
+/// ```
+/// #[derive(dataview::Pod)]
+/// #[repr(C)]
+/// struct Foo {
+/// 	field1: i8,
+/// 	field2: [u8; 3],
+/// }
+///
+/// dataview::static_assert_pod!(Foo, [Foo; 2], u32);
+/// ```
+///
+/// The result is a compiletime error if any of the types does not implement `Pod`:
+///
+/// ```compile_fail
+/// dataview::static_assert_pod!(bool);
+/// ```
+#[macro_export]
+macro_rules! static_assert_pod {
+	($($ty:ty),+ $(,)?) => {
+		const _: () = {
+			const fn assert_pod<T: ?Sized + $crate::Pod>() {}
+			$(assert_pod::<$ty>();)+
+		};
+	};
+}
+
+/// Asserts at compiletime the size, alignment and field offsets of a type.
+///
+/// ```
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: u32,
+/// 	version: u16,
+/// 	flags: u16,
+/// 	len: u64,
+/// }
+///
+/// dataview::assert_layout!(Header {
+/// 	size = 16,
+/// 	align = 8,
+/// 	magic: 0,
+/// 	version: 4,
+/// 	flags: 6,
+/// 	len: 8,
+/// });
+/// ```
+///
+/// The syntax is `$ty { size = $size, align = $align, $($field: $offset),* }` where every entry is optional.
+///
+/// The result is a compiletime error if the layout does not match, eg. `expected Offset<flags, 8>, found Offset<flags, 6>`:
+///
+/// ```compile_fail
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: u32,
+/// 	version: u16,
+/// 	flags: u16,
+/// }
+///
+/// dataview::assert_layout!(Header { size = 8, flags: 8 });
+/// ```
+#[macro_export]
+macro_rules! assert_layout {
+	($ty:ty { $($body:tt)* }) => {
+		$crate::assert_layout!(@parse [$ty] $($body)*);
+	};
+	(@parse [$ty:ty] size = $size:expr $(, $($tail:tt)*)?) => {
+		const _: () = {
+			let _: $crate::const_report::Size<$ty, { $size }> = $crate::const_report::Size::<$ty, { ::core::mem::size_of::<$ty>() }>(::core::marker::PhantomData);
+		};
+		$crate::assert_layout!(@parse [$ty] $($($tail)*)?);
+	};
+	(@parse [$ty:ty] align = $align:expr $(, $($tail:tt)*)?) => {
+		const _: () = {
+			let _: $crate::const_report::Align<$ty, { $align }> = $crate::const_report::Align::<$ty, { ::core::mem::align_of::<$ty>() }>(::core::marker::PhantomData);
+		};
+		$crate::assert_layout!(@parse [$ty] $($($tail)*)?);
+	};
+	(@parse [$ty:ty] $field:ident: $offset:expr $(, $($tail:tt)*)?) => {
+		const _: () = {
+			#[allow(non_camel_case_types)]
+			struct $field;
+			let _: $crate::const_report::Offset<$field, { $offset }> = $crate::const_report::Offset::<$field, { $crate::offset_of!($ty.$field) }>(::core::marker::PhantomData);
+		};
+		$crate::assert_layout!(@parse [$ty] $($($tail)*)?);
+	};
+	(@parse [$ty:ty]) => {};
+}
@@ -0,0 +1,181 @@
+use core::sync::atomic::*;
+use super::*;
+use crate::data_view::invalid_offset;
+
+mod private {
+	pub trait Sealed {}
+}
+
+/// Pod integer types with native atomic operations.
+///
+/// This trait is sealed and implemented for the integer types the target supports atomic operations for.
+/// See the atomic methods on [`DataView`].
+///
+/// # Safety
+///
+/// `Atomic` must be the atomic type with the same size as `Self` and the operations must be atomic.
+pub unsafe trait AtomicPod: Pod + Copy + private::Sealed {
+	/// The atomic integer type, eg. `AtomicU32` for `u32`.
+	type Atomic: Sync;
+	#[doc(hidden)]
+	unsafe fn atomic_load(ptr: *const Self, order: Ordering) -> Self;
+	#[doc(hidden)]
+	unsafe fn atomic_store(ptr: *mut Self, value: Self, order: Ordering);
+	#[doc(hidden)]
+	unsafe fn atomic_compare_exchange(ptr: *mut Self, current: Self, new: Self, success: Ordering, failure: Ordering) -> Result<Self, Self>;
+}
+
+macro_rules! impl_atomic_pod {
+	($width:literal: $($ty:ty => $atomic:ty),*) => {
+		$(
+			#[cfg(target_has_atomic = $width)]
+			impl private::Sealed for $ty {}
+			#[cfg(target_has_atomic = $width)]
+			unsafe impl AtomicPod for $ty {
+				type Atomic = $atomic;
+				#[inline]
+				unsafe fn atomic_load(ptr: *const $ty, order: Ordering) -> $ty {
+					(*(ptr as *const $atomic)).load(order)
+				}
+				#[inline]
+				unsafe fn atomic_store(ptr: *mut $ty, value: $ty, order: Ordering) {
+					(*(ptr as *const $atomic)).store(value, order)
+				}
+				#[inline]
+				unsafe fn atomic_compare_exchange(ptr: *mut $ty, current: $ty, new: $ty, success: Ordering, failure: Ordering) -> Result<$ty, $ty> {
+					(*(ptr as *const $atomic)).compare_exchange(current, new, success, failure)
+				}
+			}
+		)*
+	};
+}
+
+impl_atomic_pod!("8": i8 => AtomicI8, u8 => AtomicU8);
+impl_atomic_pod!("16": i16 => AtomicI16, u16 => AtomicU16);
+impl_atomic_pod!("32": i32 => AtomicI32, u32 => AtomicU32);
+impl_atomic_pod!("64": i64 => AtomicI64, u64 => AtomicU64);
+impl_atomic_pod!("ptr": isize => AtomicIsize, usize => AtomicUsize);
+
+/// Atomic operations on aligned integers in the view.
+///
+/// Stores and exchanges require exclusive access to the view.
+/// A shared `&DataView` is immutable memory: the bytes are not wrapped in `UnsafeCell` and mutating them, even atomically, is undefined behavior.
+///
+/// To share lock-free flags and counters between threads, borrow the view mutably once with [`get_atomic`](DataView::get_atomic)
+/// and share the returned atomic reference instead.
+impl DataView {
+	/// Atomically loads an aligned integer from the view.
+	#[inline]
+	pub fn try_atomic_load<T: AtomicPod>(&self, offset: usize, order: Ordering) -> Option<T> {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.as_ref().get(index)?;
+		let unaligned_ptr = bytes.as_ptr() as *const T;
+		if !is_aligned(unaligned_ptr as *const T::Atomic) {
+			return None;
+		}
+		unsafe {
+			Some(T::atomic_load(unaligned_ptr, order))
+		}
+	}
+	/// Atomically loads an aligned integer from the view.
+	#[track_caller]
+	#[inline]
+	pub fn atomic_load<T: AtomicPod>(&self, offset: usize, order: Ordering) -> T {
+		match self.try_atomic_load(offset, order) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Atomically stores an aligned integer into the view.
+	#[inline]
+	pub fn try_atomic_store<T: AtomicPod>(&mut self, offset: usize, value: T, order: Ordering) -> Option<()> {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.as_mut().get_mut(index)?;
+		let unaligned_ptr = bytes.as_mut_ptr() as *mut T;
+		if !is_aligned(unaligned_ptr as *const T::Atomic) {
+			return None;
+		}
+		unsafe {
+			T::atomic_store(unaligned_ptr, value, order);
+			Some(())
+		}
+	}
+	/// Atomically stores an aligned integer into the view.
+	#[track_caller]
+	#[inline]
+	pub fn atomic_store<T: AtomicPod>(&mut self, offset: usize, value: T, order: Ordering) {
+		match self.try_atomic_store(offset, value, order) {
+			Some(()) => (),
+			None => invalid_offset(),
+		}
+	}
+	/// Atomically stores an integer into the view if the current value is the same as the `current` value.
+	///
+	/// See [`AtomicU32::compare_exchange`] for more information.
+	#[doc(alias = "compare_exchange_at")]
+	#[inline]
+	pub fn try_atomic_compare_exchange<T: AtomicPod>(&mut self, offset: usize, current: T, new: T, success: Ordering, failure: Ordering) -> Option<Result<T, T>> {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.as_mut().get_mut(index)?;
+		let unaligned_ptr = bytes.as_mut_ptr() as *mut T;
+		if !is_aligned(unaligned_ptr as *const T::Atomic) {
+			return None;
+		}
+		unsafe {
+			Some(T::atomic_compare_exchange(unaligned_ptr, current, new, success, failure))
+		}
+	}
+	/// Atomically stores an integer into the view if the current value is the same as the `current` value.
+	///
+	/// See [`AtomicU32::compare_exchange`] for more information.
+	#[doc(alias = "compare_exchange_at")]
+	#[track_caller]
+	#[inline]
+	pub fn atomic_compare_exchange<T: AtomicPod>(&mut self, offset: usize, current: T, new: T, success: Ordering, failure: Ordering) -> Result<T, T> {
+		match self.try_atomic_compare_exchange(offset, current, new, success, failure) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Gets an aligned atomic reference into the view.
+	///
+	/// The view stays mutably borrowed while the atomic is in use, the atomic itself can be shared between threads.
+	///
+	/// ```
+	/// use std::sync::atomic::Ordering;
+	///
+	/// let mut header = [0u32; 4];
+	/// let view = dataview::DataView::from_mut(&mut header);
+	/// let counter = view.get_atomic::<u32>(4);
+	/// std::thread::scope(|s| {
+	/// 	for _ in 0..4 {
+	/// 		s.spawn(|| counter.fetch_add(1, Ordering::Relaxed));
+	/// 	}
+	/// });
+	/// assert_eq!(header[1], 4);
+	/// ```
+	#[inline]
+	pub fn try_get_atomic<T: AtomicPod>(&mut self, offset: usize) -> Option<&T::Atomic> {
+		let index = offset..offset.checked_add(mem::size_of::<T>())?;
+		let bytes = self.as_mut().get_mut(index)?;
+		let ptr = bytes.as_mut_ptr() as *const T::Atomic;
+		if !is_aligned(ptr) {
+			return None;
+		}
+		// The exclusive borrow of the bytes is handed to the atomic which allows shared mutation
+		unsafe {
+			Some(&*ptr)
+		}
+	}
+	/// Gets an aligned atomic reference into the view.
+	///
+	/// The view stays mutably borrowed while the atomic is in use, the atomic itself can be shared between threads.
+	#[track_caller]
+	#[inline]
+	pub fn get_atomic<T: AtomicPod>(&mut self, offset: usize) -> &T::Atomic {
+		match self.try_get_atomic::<T>(offset) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
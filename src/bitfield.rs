@@ -0,0 +1,223 @@
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// Defines a wrapper around an unsigned integer with named bit ranges.
+///
+/// Each field declares its getter and setter names followed by a half-open range of bits, counting from the least significant bit:
+///
+/// ```
+/// dataview::bitfield! {
+/// 	#[derive(Copy, Clone, Debug, Default)]
+/// 	pub struct Status(u16) {
+/// 		/// Device is ready.
+/// 		pub ready, set_ready: 0..1,
+/// 		pub mode, set_mode: 1..4,
+/// 		pub count, set_count: 8..16,
+/// 	}
+/// }
+///
+/// let mut status = Status(0);
+/// status.set_ready(1);
+/// status.set_mode(0b101);
+/// status.set_count(0x1ff); // Truncated to fit
+/// assert_eq!(status.0, 0xff0b);
+/// assert_eq!(status.mode(), 0b101);
+///
+/// // The wrapper is Pod
+/// let view = dataview::DataView::from(&[0x0b_u8, 0x12]);
+/// assert_eq!(view.read::<Status>(0).count(), u16::from_ne_bytes([0x0b, 0x12]) >> 8);
+/// ```
+///
+/// The struct is a transparent wrapper around the integer and implements [`Pod`](crate::Pod).
+/// Getters are `const fn` and return the bits shifted down, setters truncate the value to the width of the field.
+///
+/// Ranges outside the integer fail to compile:
+///
+/// ```compile_fail
+/// dataview::bitfield! {
+/// 	struct Reg(u8) {
+/// 		high, set_high: 4..9,
+/// 	}
+/// }
+/// ```
+///
+/// Signed integers fail to compile, their arithmetic shifts would smear the sign bit into the masks:
+///
+/// ```compile_fail
+/// dataview::bitfield! {
+/// 	struct Reg(i16) {
+/// 		low, set_low: 0..4,
+/// 	}
+/// }
+/// ```
+#[macro_export]
+macro_rules! bitfield {
+	(
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident($ty:ty) {
+			$(
+				$(#[$field_meta:meta])*
+				$field_vis:vis $get:ident, $set:ident: $lo:literal..$hi:literal
+			),*
+			$(,)?
+		}
+	) => {
+		$(#[$meta])*
+		#[repr(transparent)]
+		$vis struct $name(pub $ty);
+
+		unsafe impl $crate::Pod for $name where $ty: $crate::Pod {}
+
+		const _: () = ::core::assert!(<$ty>::MIN == 0, "bitfield requires an unsigned integer");
+
+		#[allow(dead_code)]
+		impl $name {
+			$(
+				$(#[$field_meta])*
+				#[inline]
+				$field_vis const fn $get(&self) -> $ty {
+					const _: () = ::core::assert!($lo < $hi && $hi <= <$ty>::BITS, "bit range out of bounds");
+					(self.0 >> $lo) & (!0 >> (<$ty>::BITS - ($hi - $lo)))
+				}
+				$(#[$field_meta])*
+				#[inline]
+				$field_vis fn $set(&mut self, value: $ty) {
+					let mask: $ty = !0 >> (<$ty>::BITS - ($hi - $lo));
+					self.0 = (self.0 & !(mask << $lo)) | ((value & mask) << $lo);
+				}
+			)*
+		}
+	};
+}
+
+#[inline]
+fn bits_mask(count: u32) -> u64 {
+	if count >= 64 { !0 } else { (1 << count) - 1 }
+}
+
+//----------------------------------------------------------------
+
+/// Reads bits from the view.
+///
+/// Bits are numbered starting from the least significant bit of the first byte, as if the view were a little endian integer.
+impl DataView {
+	/// Reads `count` bits starting at `bit_offset`.
+	///
+	/// Returns `None` if the bits are out of bounds or `count` is larger than 64.
+	///
+	/// ```
+	/// let view = dataview::DataView::from(&[0b1010_0000_u8, 0b0000_0011]);
+	/// assert_eq!(view.try_read_bits(5, 5), Some(0b11101));
+	/// assert_eq!(view.try_read_bits(12, 5), None);
+	/// ```
+	#[inline]
+	pub fn try_read_bits(&self, bit_offset: usize, count: u32) -> Option<u64> {
+		if count > 64 {
+			return None;
+		}
+		let end = bit_offset.checked_add(count as usize)?;
+		let bytes = self.as_ref().get(bit_offset / 8..end.checked_add(7)? / 8)?;
+		let mut value = 0u128;
+		for (i, &byte) in bytes.iter().enumerate() {
+			value |= (byte as u128) << (i * 8);
+		}
+		Some((value >> (bit_offset % 8)) as u64 & bits_mask(count))
+	}
+	/// Reads `count` bits starting at `bit_offset`.
+	#[track_caller]
+	#[inline]
+	pub fn read_bits(&self, bit_offset: usize, count: u32) -> u64 {
+		match self.try_read_bits(bit_offset, count) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Writes bits into the view.
+///
+/// Bits are numbered starting from the least significant bit of the first byte, as if the view were a little endian integer.
+impl DataView {
+	/// Writes the low `count` bits of `value` starting at `bit_offset`, leaving the surrounding bits untouched.
+	///
+	/// Returns `None` if the bits are out of bounds or `count` is larger than 64.
+	///
+	/// ```
+	/// let mut bytes = [0xff_u8; 2];
+	/// let view = dataview::DataView::from_mut(&mut bytes);
+	/// view.try_write_bits(6, 4, 0).unwrap();
+	/// assert_eq!(bytes, [0b0011_1111, 0b1111_1100]);
+	/// ```
+	#[inline]
+	pub fn try_write_bits(&mut self, bit_offset: usize, count: u32, value: u64) -> Option<()> {
+		if count > 64 {
+			return None;
+		}
+		let end = bit_offset.checked_add(count as usize)?;
+		let bytes = self.as_mut().get_mut(bit_offset / 8..end.checked_add(7)? / 8)?;
+		let shift = bit_offset % 8;
+		let mask = (bits_mask(count) as u128) << shift;
+		let value = ((value as u128) << shift) & mask;
+		for (i, byte) in bytes.iter_mut().enumerate() {
+			let byte_mask = (mask >> (i * 8)) as u8;
+			*byte = (*byte & !byte_mask) | (value >> (i * 8)) as u8;
+		}
+		Some(())
+	}
+	/// Writes the low `count` bits of `value` starting at `bit_offset`, leaving the surrounding bits untouched.
+	#[track_caller]
+	#[inline]
+	pub fn write_bits(&mut self, bit_offset: usize, count: u32, value: u64) {
+		match self.try_write_bits(bit_offset, count, value) {
+			Some(()) => (),
+			None => invalid_offset(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::DataView;
+
+	crate::bitfield! {
+		#[derive(Copy, Clone, Debug, PartialEq)]
+		struct Reg(u32) {
+			low, set_low: 0..4,
+			full, set_full: 0..32,
+			top, set_top: 31..32,
+		}
+	}
+
+	#[test]
+	fn getters_setters() {
+		let mut reg = Reg(0);
+		reg.set_top(3);
+		reg.set_low(0x1f);
+		assert_eq!(reg, Reg(0x8000_000f));
+		assert_eq!(reg.top(), 1);
+		reg.set_full(0x1234_5678);
+		assert_eq!(reg.low(), 8);
+		const REG: Reg = Reg(0xf0);
+		const LOW: u32 = REG.low();
+		assert_eq!(LOW, 0);
+	}
+
+	#[test]
+	fn read_write_bits() {
+		let mut bytes = [0u8; 10];
+		let view = DataView::from_mut(&mut bytes);
+		view.write_bits(3, 64, !0);
+		assert_eq!(view.read_bits(3, 64), !0);
+		assert_eq!(view.read_bits(0, 3), 0);
+		assert_eq!(view.read_bits(67, 13), 0);
+		view.write_bits(7, 2, 0b10);
+		assert_eq!(view.read_bits(5, 6), 0b111011);
+		assert_eq!(view.try_read_bits(0, 65), None);
+		assert_eq!(view.try_read_bits(80, 0), Some(0));
+		assert_eq!(view.try_read_bits(81, 0), None);
+		assert_eq!(view.try_write_bits(79, 2, 0), None);
+		assert_eq!(view.try_read_bits(usize::MAX, 1), None);
+	}
+}
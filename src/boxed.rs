@@ -0,0 +1,50 @@
+use core::ptr::NonNull;
+use alloc::alloc::{alloc_zeroed, handle_alloc_error, Layout as AllocLayout};
+use alloc::boxed::Box;
+use super::*;
+
+/// Returns a zero-initialized instance of the type on the heap.
+///
+/// Unlike `Box::new(zeroed())` the value is never constructed on the stack, large values do not overflow the stack.
+///
+/// ```
+/// let table: Box<[u64; 0x100000]> = dataview::boxed_zeroed();
+/// assert!(table.iter().all(|&value| value == 0));
+/// ```
+pub fn boxed_zeroed<T: Zeroable>() -> Box<T> {
+	let layout = AllocLayout::new::<T>();
+	unsafe { Box::from_raw(allocate_zeroed::<T>(layout)) }
+}
+
+/// Returns a zero-initialized slice of the type on the heap.
+///
+/// The memory is allocated zeroed directly.
+///
+/// ```
+/// let slice: Box<[u32]> = dataview::boxed_slice_zeroed(1000);
+/// assert_eq!(slice.len(), 1000);
+/// assert!(slice.iter().all(|&value| value == 0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the size of the slice in bytes overflows `isize::MAX`.
+pub fn boxed_slice_zeroed<T: Zeroable>(len: usize) -> Box<[T]> {
+	let layout = match AllocLayout::array::<T>(len) {
+		Ok(layout) => layout,
+		Err(_) => panic!("capacity overflow"),
+	};
+	unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(allocate_zeroed::<T>(layout), len)) }
+}
+
+// Allocates zeroed memory for the layout of one or more T, zero sized layouts return a dangling pointer
+fn allocate_zeroed<T>(layout: AllocLayout) -> *mut T {
+	if layout.size() == 0 {
+		return NonNull::<T>::dangling().as_ptr();
+	}
+	let ptr = unsafe { alloc_zeroed(layout) };
+	if ptr.is_null() {
+		handle_alloc_error(layout);
+	}
+	ptr as *mut T
+}
@@ -0,0 +1,407 @@
+use core::{borrow, ops};
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// Owned growable byte buffer which derefs to [`DataView`].
+///
+/// ```
+/// use dataview::DataViewBuf;
+///
+/// let mut buf = DataViewBuf::new();
+/// let offset = buf.push(&0x1234_u16);
+/// buf.push(&[1u8, 2, 3]);
+/// assert_eq!(offset, 0);
+/// assert_eq!(buf.len(), 5);
+/// assert_eq!(buf.read::<u16>(0), 0x1234);
+///
+/// let bytes: Vec<u8> = buf.into();
+/// assert_eq!(bytes[2..], [1, 2, 3]);
+/// ```
+///
+/// The bytes are only aligned to 1 byte, prefer the unaligned `read` and `write` methods over `get` and `slice`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DataViewBuf {
+	bytes: Vec<u8>,
+}
+
+impl DataViewBuf {
+	/// Creates a new empty buffer.
+	#[inline]
+	pub const fn new() -> DataViewBuf {
+		DataViewBuf { bytes: Vec::new() }
+	}
+	/// Creates a new empty buffer with at least the specified capacity.
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> DataViewBuf {
+		DataViewBuf { bytes: Vec::with_capacity(capacity) }
+	}
+	/// Creates a new buffer of zeroes.
+	#[inline]
+	pub fn zeroed(len: usize) -> DataViewBuf {
+		DataViewBuf { bytes: alloc::vec![0; len] }
+	}
+	/// Returns the number of bytes the buffer can hold without reallocating.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.bytes.capacity()
+	}
+	/// Reserves capacity for at least `additional` more bytes.
+	#[inline]
+	pub fn reserve(&mut self, additional: usize) {
+		self.bytes.reserve(additional)
+	}
+	/// Reserves capacity for exactly `additional` more bytes.
+	#[inline]
+	pub fn reserve_exact(&mut self, additional: usize) {
+		self.bytes.reserve_exact(additional)
+	}
+	/// Shrinks the capacity of the buffer as much as possible.
+	#[inline]
+	pub fn shrink_to_fit(&mut self) {
+		self.bytes.shrink_to_fit()
+	}
+	/// Shortens the buffer to `len` bytes.
+	#[inline]
+	pub fn truncate(&mut self, len: usize) {
+		self.bytes.truncate(len)
+	}
+	/// Splits the buffer in two at the offset.
+	///
+	/// Returns the bytes from `at` onwards, the buffer keeps the bytes before `at`.
+	///
+	/// # Panics
+	///
+	/// Panics if `at` is greater than the length of the buffer.
+	#[track_caller]
+	#[inline]
+	pub fn split_off(&mut self, at: usize) -> DataViewBuf {
+		if at > self.bytes.len() {
+			invalid_offset();
+		}
+		DataViewBuf { bytes: self.bytes.split_off(at) }
+	}
+	/// Resizes the buffer to `len` bytes, new bytes are zeroed.
+	#[inline]
+	pub fn resize(&mut self, len: usize) {
+		self.bytes.resize(len, 0)
+	}
+	/// Removes all the bytes.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bytes.clear()
+	}
+	/// Appends the value to the end of the buffer.
+	///
+	/// Returns the offset of the value in the buffer.
+	#[inline]
+	pub fn push<T: ?Sized + AsBytes>(&mut self, value: &T) -> usize {
+		self.extend_from_slice(bytes(value))
+	}
+	/// Appends the bytes of the view to the end of the buffer.
+	///
+	/// Returns the offset of the bytes in the buffer.
+	#[inline]
+	pub fn extend_from_view(&mut self, view: &DataView) -> usize {
+		self.extend_from_slice(view.as_ref())
+	}
+	/// Appends the bytes to the end of the buffer.
+	///
+	/// Returns the offset of the bytes in the buffer.
+	#[inline]
+	pub fn extend_from_slice(&mut self, bytes: &[u8]) -> usize {
+		let offset = self.bytes.len();
+		self.bytes.extend_from_slice(bytes);
+		offset
+	}
+	/// Pads the buffer with the fill byte to a multiple of the alignment.
+	///
+	/// The alignment must be a power of two.
+	/// Returns the new length of the buffer.
+	#[track_caller]
+	#[inline]
+	pub fn align_to(&mut self, align: usize, fill: u8) -> usize {
+		debug_assert!(align.is_power_of_two());
+		let len = self.bytes.len();
+		let end = match len.checked_add(align - 1) {
+			Some(end) => end & !(align - 1),
+			None => invalid_offset(),
+		};
+		self.bytes.resize(end, fill);
+		end
+	}
+	/// Pads the buffer with the fill byte up to the offset.
+	///
+	/// Errors if the buffer is already longer than the offset.
+	/// Returns the new length of the buffer.
+	#[inline]
+	pub fn try_pad_to(&mut self, offset: usize, fill: u8) -> Option<usize> {
+		if offset < self.bytes.len() {
+			return None;
+		}
+		self.bytes.resize(offset, fill);
+		Some(offset)
+	}
+	/// Pads the buffer with the fill byte up to the offset.
+	///
+	/// Panics if the buffer is already longer than the offset.
+	/// Returns the new length of the buffer.
+	#[track_caller]
+	#[inline]
+	pub fn pad_to(&mut self, offset: usize, fill: u8) -> usize {
+		match self.try_pad_to(offset, fill) {
+			Some(len) => len,
+			None => invalid_offset(),
+		}
+	}
+	/// Returns the buffer as a data view.
+	#[inline]
+	pub fn as_data_view(&self) -> &DataView {
+		DataView::from(self.bytes.as_slice())
+	}
+	/// Returns the buffer as a mutable data view.
+	#[inline]
+	pub fn as_data_view_mut(&mut self) -> &mut DataView {
+		DataView::from_mut(self.bytes.as_mut_slice())
+	}
+	/// Returns the underlying bytes.
+	#[inline]
+	pub fn into_vec(self) -> Vec<u8> {
+		self.bytes
+	}
+}
+
+impl ops::Deref for DataViewBuf {
+	type Target = DataView;
+	#[inline]
+	fn deref(&self) -> &DataView {
+		self.as_data_view()
+	}
+}
+impl ops::DerefMut for DataViewBuf {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut DataView {
+		self.as_data_view_mut()
+	}
+}
+
+impl AsRef<[u8]> for DataViewBuf {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		&self.bytes
+	}
+}
+impl AsMut<[u8]> for DataViewBuf {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [u8] {
+		&mut self.bytes
+	}
+}
+
+impl borrow::Borrow<DataView> for DataViewBuf {
+	#[inline]
+	fn borrow(&self) -> &DataView {
+		self.as_data_view()
+	}
+}
+impl borrow::BorrowMut<DataView> for DataViewBuf {
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut DataView {
+		self.as_data_view_mut()
+	}
+}
+
+/// Data views can be used in `Cow<DataView>`.
+///
+/// ```
+/// use std::borrow::Cow;
+/// use dataview::DataView;
+///
+/// let value = 42u32;
+/// let mut cow = Cow::Borrowed(DataView::from(&value));
+/// cow.to_mut().write(0, &13u32);
+/// assert_eq!(cow.read::<u32>(0), 13);
+/// assert_eq!(value, 42);
+/// ```
+impl ToOwned for DataView {
+	type Owned = DataViewBuf;
+	#[inline]
+	fn to_owned(&self) -> DataViewBuf {
+		DataViewBuf::from(self)
+	}
+}
+
+impl From<Vec<u8>> for DataViewBuf {
+	#[inline]
+	fn from(bytes: Vec<u8>) -> DataViewBuf {
+		DataViewBuf { bytes }
+	}
+}
+impl From<&DataView> for DataViewBuf {
+	#[inline]
+	fn from(view: &DataView) -> DataViewBuf {
+		DataViewBuf { bytes: view.as_ref().into() }
+	}
+}
+impl From<DataViewBuf> for Vec<u8> {
+	#[inline]
+	fn from(buf: DataViewBuf) -> Vec<u8> {
+		buf.bytes
+	}
+}
+
+impl From<Box<[u8]>> for Box<DataView> {
+	#[inline]
+	fn from(bytes: Box<[u8]>) -> Box<DataView> {
+		// DataView is a transparent wrapper around [u8]
+		unsafe { Box::from_raw(Box::into_raw(bytes) as *mut DataView) }
+	}
+}
+impl From<Box<DataView>> for Box<[u8]> {
+	#[inline]
+	fn from(view: Box<DataView>) -> Box<[u8]> {
+		unsafe { Box::from_raw(Box::into_raw(view) as *mut [u8]) }
+	}
+}
+impl From<&DataView> for Box<DataView> {
+	#[inline]
+	fn from(view: &DataView) -> Box<DataView> {
+		Box::<[u8]>::from(view.as_ref()).into()
+	}
+}
+impl From<DataViewBuf> for Box<DataView> {
+	#[inline]
+	fn from(buf: DataViewBuf) -> Box<DataView> {
+		buf.bytes.into_boxed_slice().into()
+	}
+}
+impl Clone for Box<DataView> {
+	#[inline]
+	fn clone(&self) -> Box<DataView> {
+		Box::<DataView>::from(&**self)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Copies the view into owned storage.
+///
+/// Use [`to_owned`](alloc::borrow::ToOwned::to_owned) to copy the view into a [`DataViewBuf`] instead.
+///
+/// ```
+/// let value = [1u16, 2, 3];
+/// let view = dataview::DataView::from(&value);
+///
+/// let bytes: Vec<u8> = view[2..4].to_vec();
+/// assert_eq!(bytes, 2u16.to_ne_bytes());
+///
+/// let boxed = view.to_boxed_slice();
+/// assert_eq!(boxed.read::<u16>(4), 3);
+/// ```
+impl DataView {
+	/// Copies the bytes of the view into a new vector.
+	#[inline]
+	pub fn to_vec(&self) -> Vec<u8> {
+		self.as_ref().to_vec()
+	}
+	/// Copies the bytes of the view into a new boxed data view.
+	#[inline]
+	pub fn to_boxed_slice(&self) -> Box<DataView> {
+		Box::<DataView>::from(self)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Creates shared data views from shared bytes.
+///
+/// ```
+/// use std::sync::Arc;
+/// use dataview::DataView;
+///
+/// let view = DataView::from_arc(vec![1u8, 0, 0, 0].into());
+/// let shared = Arc::clone(&view);
+/// std::thread::spawn(move || assert_eq!(shared.read::<u32>(0), u32::from_le(1))).join().unwrap();
+/// ```
+impl DataView {
+	/// Converts shared bytes into a shared data view.
+	#[cfg(target_has_atomic = "ptr")]
+	#[inline]
+	pub fn from_arc(bytes: Arc<[u8]>) -> Arc<DataView> {
+		// DataView is a transparent wrapper around [u8]
+		unsafe { Arc::from_raw(Arc::into_raw(bytes) as *const DataView) }
+	}
+	/// Converts a shared data view back into shared bytes.
+	#[cfg(target_has_atomic = "ptr")]
+	#[inline]
+	pub fn into_arc_bytes(view: Arc<DataView>) -> Arc<[u8]> {
+		unsafe { Arc::from_raw(Arc::into_raw(view) as *const [u8]) }
+	}
+	/// Converts shared bytes into a shared data view.
+	#[inline]
+	pub fn from_rc(bytes: Rc<[u8]>) -> Rc<DataView> {
+		// DataView is a transparent wrapper around [u8]
+		unsafe { Rc::from_raw(Rc::into_raw(bytes) as *const DataView) }
+	}
+	/// Converts a shared data view back into shared bytes.
+	#[inline]
+	pub fn into_rc_bytes(view: Rc<DataView>) -> Rc<[u8]> {
+		unsafe { Rc::from_raw(Rc::into_raw(view) as *const [u8]) }
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Copies (potentially unaligned) elements from the view into a new vector.
+impl DataView {
+	/// Copies (potentially unaligned) elements from the view into a new vector.
+	///
+	/// Unlike [`try_slice`](DataView::try_slice) this does not require the elements to be aligned in the view.
+	#[inline]
+	pub fn try_collect_vec<T: AnyBitPattern>(&self, offset: usize, len: usize) -> Option<Vec<T>> {
+		if !self.in_bounds_slice::<T>(offset, len) {
+			return None;
+		}
+		let mut vec = Vec::<T>::with_capacity(len);
+		unsafe {
+			let src = self.as_ref().as_ptr().add(offset);
+			ptr::copy_nonoverlapping(src, vec.as_mut_ptr() as *mut u8, len * mem::size_of::<T>());
+			vec.set_len(len);
+		}
+		Some(vec)
+	}
+	/// Copies (potentially unaligned) elements from the view into a new vector.
+	#[track_caller]
+	#[inline]
+	pub fn collect_vec<T: AnyBitPattern>(&self, offset: usize, len: usize) -> Vec<T> {
+		match self.try_collect_vec(offset, len) {
+			Some(vec) => vec,
+			None => invalid_offset(),
+		}
+	}
+}
+
+/// Copies (potentially unaligned) elements from a byte slice into a new vector.
+///
+/// Returns `None` if the length of the bytes is not a multiple of the size of the element.
+///
+/// ```
+/// let bytes = [0, 1, 0, 2, 0];
+/// let values: Vec<u16> = dataview::collect_vec(&bytes[1..]).unwrap();
+/// assert_eq!(values, [u16::from_ne_bytes([1, 0]), u16::from_ne_bytes([2, 0])]);
+/// assert_eq!(dataview::collect_vec::<u16>(&bytes), None);
+/// ```
+#[inline]
+pub fn collect_vec<T: AnyBitPattern>(bytes: &[u8]) -> Option<Vec<T>> {
+	let len = match mem::size_of::<T>() {
+		0 => 0,
+		size if bytes.len() / size * size == bytes.len() => bytes.len() / size,
+		_ => return None,
+	};
+	DataView::from(bytes).try_collect_vec(0, len)
+}
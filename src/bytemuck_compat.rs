@@ -0,0 +1,57 @@
+use super::*;
+
+/// Wrapper bridging `bytemuck` and dataview Pod types.
+///
+/// Wrapping a [`bytemuck::Pod`] type makes it a dataview [`Pod`], and wrapping a dataview Pod type makes it a [`bytemuck::Pod`].
+/// The wrapper is transparent and implements [`bytemuck::TransparentWrapper`] to convert references and slices in place.
+///
+/// ```
+/// use dataview::{Bytemuck, DataView};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, bytemuck::Zeroable, bytemuck::Pod)]
+/// #[repr(C)]
+/// struct Vertex {
+/// 	pos: [f32; 2],
+/// }
+///
+/// let mut bytes = [0u8; 8];
+/// let view = DataView::from_mut(&mut bytes);
+/// view.write(0, &Bytemuck(Vertex { pos: [1.0, 2.0] }));
+/// assert_eq!(view.read::<Bytemuck<Vertex>>(0).0, Vertex { pos: [1.0, 2.0] });
+///
+/// // And the other way around
+/// let pair: Bytemuck<[u16; 2]> = bytemuck::cast(0x0001_0001u32);
+/// assert_eq!(pair.0, [1, 1]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Bytemuck<T>(pub T);
+
+unsafe impl<T: bytemuck::Pod> Pod for Bytemuck<T> {}
+
+unsafe impl<T: Pod> bytemuck::Zeroable for Bytemuck<T> {}
+unsafe impl<T: Pod + Copy> bytemuck::Pod for Bytemuck<T> {}
+unsafe impl<T> bytemuck::TransparentWrapper<T> for Bytemuck<T> {}
+
+impl<T> From<T> for Bytemuck<T> {
+	#[inline]
+	fn from(value: T) -> Bytemuck<T> {
+		Bytemuck(value)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Views `bytemuck` types.
+impl DataView {
+	/// Returns a data view into the bytes of a `bytemuck` type.
+	#[inline]
+	pub fn from_bytemuck<T: bytemuck::NoUninit>(v: &T) -> &DataView {
+		DataView::from(bytemuck::bytes_of(v))
+	}
+	/// Returns a mutable data view into the bytes of a `bytemuck` type.
+	#[inline]
+	pub fn from_bytemuck_mut<T: bytemuck::Pod>(v: &mut T) -> &mut DataView {
+		DataView::from_mut(bytemuck::bytes_of_mut(v))
+	}
+}
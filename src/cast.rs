@@ -0,0 +1,189 @@
+/*!
+Safe casting between pod types.
+
+```
+let words = [0x01020304u32, 0x05060708];
+let chunks: &[[u8; 4]] = dataview::cast::try_cast_slice(&words).unwrap();
+assert_eq!(chunks[1], 0x05060708u32.to_ne_bytes());
+```
+*/
+
+use core::{fmt, ptr, slice};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use super::*;
+
+/// Errors produced by the casting functions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CastError {
+	/// The size of the source does not match or is not a multiple of the size of the destination type.
+	SizeMismatch,
+	/// The source is misaligned for the destination type.
+	Misaligned,
+}
+
+impl fmt::Display for CastError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			CastError::SizeMismatch => "size mismatch",
+			CastError::Misaligned => "misaligned",
+		})
+	}
+}
+
+/// Casts a value to a value of another type with the same size.
+#[inline]
+pub fn try_cast<A: AsBytes, B: AnyBitPattern>(value: A) -> Result<B, CastError> {
+	if mem::size_of::<A>() != mem::size_of::<B>() {
+		return Err(CastError::SizeMismatch);
+	}
+	let value = mem::ManuallyDrop::new(value);
+	unsafe {
+		Ok(ptr::read_unaligned(&*value as *const A as *const B))
+	}
+}
+
+/// Casts a value to a value of another type with the same size.
+///
+/// # Panics
+///
+/// Panics if the types have different sizes.
+#[track_caller]
+#[inline]
+pub fn cast<A: AsBytes, B: AnyBitPattern>(value: A) -> B {
+	match try_cast(value) {
+		Ok(value) => value,
+		Err(err) => cast_failed(err),
+	}
+}
+
+/// Casts a reference to a reference of another type with the same size.
+#[inline]
+pub fn try_cast_ref<A: AsBytes, B: AnyBitPattern>(value: &A) -> Result<&B, CastError> {
+	if mem::size_of::<A>() != mem::size_of::<B>() {
+		return Err(CastError::SizeMismatch);
+	}
+	let ptr = value as *const A as *const B;
+	if !is_aligned(ptr) {
+		return Err(CastError::Misaligned);
+	}
+	unsafe {
+		Ok(&*ptr)
+	}
+}
+
+/// Casts a mutable reference to a mutable reference of another type with the same size.
+#[inline]
+pub fn try_cast_mut<A: Pod, B: Pod>(value: &mut A) -> Result<&mut B, CastError> {
+	if mem::size_of::<A>() != mem::size_of::<B>() {
+		return Err(CastError::SizeMismatch);
+	}
+	let ptr = value as *mut A as *mut B;
+	if !is_aligned(ptr) {
+		return Err(CastError::Misaligned);
+	}
+	unsafe {
+		Ok(&mut *ptr)
+	}
+}
+
+// Returns the length of the destination slice
+#[inline]
+fn cast_slice_len<A, B>(len: usize) -> Result<usize, CastError> {
+	let size = len * mem::size_of::<A>();
+	match mem::size_of::<B>() {
+		0 if size == 0 => Ok(len),
+		0 => Err(CastError::SizeMismatch),
+		b_size if size / b_size * b_size != size => Err(CastError::SizeMismatch),
+		b_size => Ok(size / b_size),
+	}
+}
+
+/// Casts a slice to a slice of another type covering the same bytes.
+#[inline]
+pub fn try_cast_slice<A: AsBytes, B: AnyBitPattern>(value: &[A]) -> Result<&[B], CastError> {
+	let len = cast_slice_len::<A, B>(value.len())?;
+	let ptr = value.as_ptr() as *const B;
+	if !is_aligned(ptr) {
+		return Err(CastError::Misaligned);
+	}
+	unsafe {
+		Ok(slice::from_raw_parts(ptr, len))
+	}
+}
+
+/// Casts a mutable slice to a mutable slice of another type covering the same bytes.
+#[inline]
+pub fn try_cast_slice_mut<A: Pod, B: Pod>(value: &mut [A]) -> Result<&mut [B], CastError> {
+	let len = cast_slice_len::<A, B>(value.len())?;
+	let ptr = value.as_mut_ptr() as *mut B;
+	if !is_aligned(ptr) {
+		return Err(CastError::Misaligned);
+	}
+	unsafe {
+		Ok(slice::from_raw_parts_mut(ptr, len))
+	}
+}
+
+/// Casts a vector to a vector of another type covering the same bytes without copying.
+///
+/// The allocation is reused which requires both types to have the same alignment,
+/// and both the length and capacity in bytes must be a multiple of the size of the destination type.
+/// Returns the error and the original vector if the cast is not possible.
+///
+/// ```
+/// use dataview::cast::try_cast_vec;
+///
+/// let words: Vec<[u8; 4]> = vec![[1, 2, 3, 4], [5, 6, 7, 8]];
+/// let bytes: Vec<u8> = try_cast_vec(words).unwrap();
+/// assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn try_cast_vec<A: AsBytes, B: AnyBitPattern>(vec: Vec<A>) -> Result<Vec<B>, (CastError, Vec<A>)> {
+	if mem::align_of::<A>() != mem::align_of::<B>() {
+		return Err((CastError::Misaligned, vec));
+	}
+	let (len, capacity) = match (cast_slice_len::<A, B>(vec.len()), cast_slice_len::<A, B>(vec.capacity())) {
+		(Ok(len), Ok(capacity)) => (len, capacity),
+		_ => return Err((CastError::SizeMismatch, vec)),
+	};
+	let mut vec = mem::ManuallyDrop::new(vec);
+	unsafe {
+		Ok(Vec::from_raw_parts(vec.as_mut_ptr() as *mut B, len, capacity))
+	}
+}
+
+/// Casts a vector to a vector of another type covering the same bytes.
+///
+/// Reuses the allocation if possible, see [`try_cast_vec`], otherwise copies the elements into a new vector.
+///
+/// ```
+/// let bytes = vec![1u8, 0, 0, 0, 2, 0, 0, 0];
+/// let words: Vec<u32> = dataview::cast::cast_vec(bytes);
+/// assert_eq!(words, [u32::from_le(1), u32::from_le(2)]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the length in bytes is not a multiple of the size of the destination type.
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn cast_vec<A: AsBytes, B: AnyBitPattern>(vec: Vec<A>) -> Vec<B> {
+	match try_cast_vec(vec) {
+		Ok(vec) => vec,
+		Err((_, vec)) => match cast_slice_len::<A, B>(vec.len()) {
+			Ok(len) => match try_cast_slice::<A, u8>(&vec) {
+				Ok(bytes) => DataView::from(bytes).collect_vec(0, len),
+				Err(err) => cast_failed(err),
+			},
+			Err(err) => cast_failed(err),
+		},
+	}
+}
+
+#[cold]
+#[track_caller]
+#[inline(never)]
+fn cast_failed(err: CastError) -> ! {
+	panic!("cast failed: {}", err)
+}
@@ -0,0 +1,302 @@
+/*!
+Checksums over byte buffers.
+
+Every checksum is an incremental state fed with [`update`](Crc32::update) and finalized with `finish`.
+The [`DataView`] methods checksum a range of the view in one call:
+
+```
+use dataview::checksum::{Crc32, Adler32};
+
+let view = dataview::DataView::from(b"123456789");
+assert_eq!(view.crc32(..), 0xcbf43926);
+
+let mut crc = Crc32::new();
+crc.update(&view[..4]);
+crc.update(&view[4..]);
+assert_eq!(crc.finish(), 0xcbf43926);
+
+assert_eq!(Adler32::checksum(b"Wikipedia"), 0x11e60398);
+```
+
+The tables are computed at compiletime.
+*/
+
+use core::ops;
+use super::*;
+use crate::data_view::invalid_offset;
+
+const fn crc32_table(poly: u32) -> [u32; 256] {
+	let mut table = [0u32; 256];
+	let mut i = 0;
+	while i < 256 {
+		let mut crc = i as u32;
+		let mut bit = 0;
+		while bit < 8 {
+			crc = if crc & 1 != 0 { (crc >> 1) ^ poly } else { crc >> 1 };
+			bit += 1;
+		}
+		table[i] = crc;
+		i += 1;
+	}
+	table
+}
+
+const fn crc16_table(poly: u16) -> [u16; 256] {
+	let mut table = [0u16; 256];
+	let mut i = 0;
+	while i < 256 {
+		let mut crc = (i as u16) << 8;
+		let mut bit = 0;
+		while bit < 8 {
+			crc = if crc & 0x8000 != 0 { (crc << 1) ^ poly } else { crc << 1 };
+			bit += 1;
+		}
+		table[i] = crc;
+		i += 1;
+	}
+	table
+}
+
+static CRC32_TABLE: [u32; 256] = crc32_table(0xedb88320);
+static CRC16_TABLE: [u16; 256] = crc16_table(0x1021);
+
+/// CRC-32 as used by zlib, PNG and Ethernet (reflected polynomial `0xedb88320`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Crc32 {
+	state: u32,
+}
+
+impl Crc32 {
+	/// Starts a new checksum.
+	#[inline]
+	pub const fn new() -> Crc32 {
+		Crc32 { state: !0 }
+	}
+	/// Feeds bytes into the checksum.
+	#[inline]
+	pub fn update<T: ?Sized + AsBytes>(&mut self, data: &T) {
+		let mut crc = self.state;
+		for &byte in bytes(data) {
+			crc = CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+		}
+		self.state = crc;
+	}
+	/// Returns the checksum of the bytes fed so far.
+	#[inline]
+	pub const fn finish(&self) -> u32 {
+		!self.state
+	}
+	/// Computes the checksum of the bytes.
+	#[inline]
+	pub fn checksum<T: ?Sized + AsBytes>(data: &T) -> u32 {
+		let mut crc = Crc32::new();
+		crc.update(data);
+		crc.finish()
+	}
+}
+
+impl Default for Crc32 {
+	#[inline]
+	fn default() -> Crc32 {
+		Crc32::new()
+	}
+}
+
+/// CRC-16/CCITT-FALSE (polynomial `0x1021`, initial value `0xffff`).
+///
+/// ```
+/// assert_eq!(dataview::checksum::Crc16::checksum(b"123456789"), 0x29b1);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Crc16 {
+	state: u16,
+}
+
+impl Crc16 {
+	/// Starts a new checksum.
+	#[inline]
+	pub const fn new() -> Crc16 {
+		Crc16 { state: 0xffff }
+	}
+	/// Feeds bytes into the checksum.
+	#[inline]
+	pub fn update<T: ?Sized + AsBytes>(&mut self, data: &T) {
+		let mut crc = self.state;
+		for &byte in bytes(data) {
+			crc = CRC16_TABLE[((crc >> 8) ^ byte as u16) as usize] ^ (crc << 8);
+		}
+		self.state = crc;
+	}
+	/// Returns the checksum of the bytes fed so far.
+	#[inline]
+	pub const fn finish(&self) -> u16 {
+		self.state
+	}
+	/// Computes the checksum of the bytes.
+	#[inline]
+	pub fn checksum<T: ?Sized + AsBytes>(data: &T) -> u16 {
+		let mut crc = Crc16::new();
+		crc.update(data);
+		crc.finish()
+	}
+}
+
+impl Default for Crc16 {
+	#[inline]
+	fn default() -> Crc16 {
+		Crc16::new()
+	}
+}
+
+/// Fletcher-16 checksum.
+///
+/// ```
+/// assert_eq!(dataview::checksum::Fletcher16::checksum(b"abcde"), 0xc8f0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Fletcher16 {
+	a: u32,
+	b: u32,
+}
+
+impl Fletcher16 {
+	/// Starts a new checksum.
+	#[inline]
+	pub const fn new() -> Fletcher16 {
+		Fletcher16 { a: 0, b: 0 }
+	}
+	/// Feeds bytes into the checksum.
+	#[inline]
+	pub fn update<T: ?Sized + AsBytes>(&mut self, data: &T) {
+		// Reduce every 5802 bytes, the largest block for which the sums cannot overflow
+		for chunk in bytes(data).chunks(5802) {
+			for &byte in chunk {
+				self.a += byte as u32;
+				self.b += self.a;
+			}
+			self.a %= 255;
+			self.b %= 255;
+		}
+	}
+	/// Returns the checksum of the bytes fed so far.
+	#[inline]
+	pub const fn finish(&self) -> u16 {
+		((self.b << 8) | self.a) as u16
+	}
+	/// Computes the checksum of the bytes.
+	#[inline]
+	pub fn checksum<T: ?Sized + AsBytes>(data: &T) -> u16 {
+		let mut sum = Fletcher16::new();
+		sum.update(data);
+		sum.finish()
+	}
+}
+
+/// Adler-32 checksum as used by zlib.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Adler32 {
+	a: u32,
+	b: u32,
+}
+
+impl Adler32 {
+	/// Starts a new checksum.
+	#[inline]
+	pub const fn new() -> Adler32 {
+		Adler32 { a: 1, b: 0 }
+	}
+	/// Feeds bytes into the checksum.
+	#[inline]
+	pub fn update<T: ?Sized + AsBytes>(&mut self, data: &T) {
+		// Reduce every 5552 bytes, the largest block for which the sums cannot overflow
+		for chunk in bytes(data).chunks(5552) {
+			for &byte in chunk {
+				self.a += byte as u32;
+				self.b += self.a;
+			}
+			self.a %= 65521;
+			self.b %= 65521;
+		}
+	}
+	/// Returns the checksum of the bytes fed so far.
+	#[inline]
+	pub const fn finish(&self) -> u32 {
+		(self.b << 16) | self.a
+	}
+	/// Computes the checksum of the bytes.
+	#[inline]
+	pub fn checksum<T: ?Sized + AsBytes>(data: &T) -> u32 {
+		let mut sum = Adler32::new();
+		sum.update(data);
+		sum.finish()
+	}
+}
+
+impl Default for Adler32 {
+	#[inline]
+	fn default() -> Adler32 {
+		Adler32::new()
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Computes checksums over a range of the view.
+impl DataView {
+	/// Computes the [`Crc32`] of the range.
+	#[inline]
+	pub fn try_crc32<R: ops::RangeBounds<usize>>(&self, range: R) -> Option<u32> {
+		Some(Crc32::checksum(self.index(range)?))
+	}
+	/// Computes the [`Crc32`] of the range.
+	#[track_caller]
+	#[inline]
+	pub fn crc32<R: ops::RangeBounds<usize>>(&self, range: R) -> u32 {
+		match self.try_crc32(range) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Computes the [`Crc16`] of the range.
+	#[inline]
+	pub fn try_crc16<R: ops::RangeBounds<usize>>(&self, range: R) -> Option<u16> {
+		Some(Crc16::checksum(self.index(range)?))
+	}
+	/// Computes the [`Crc16`] of the range.
+	#[track_caller]
+	#[inline]
+	pub fn crc16<R: ops::RangeBounds<usize>>(&self, range: R) -> u16 {
+		match self.try_crc16(range) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Computes the [`Fletcher16`] checksum of the range.
+	#[inline]
+	pub fn try_fletcher16<R: ops::RangeBounds<usize>>(&self, range: R) -> Option<u16> {
+		Some(Fletcher16::checksum(self.index(range)?))
+	}
+	/// Computes the [`Fletcher16`] checksum of the range.
+	#[track_caller]
+	#[inline]
+	pub fn fletcher16<R: ops::RangeBounds<usize>>(&self, range: R) -> u16 {
+		match self.try_fletcher16(range) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Computes the [`Adler32`] checksum of the range.
+	#[inline]
+	pub fn try_adler32<R: ops::RangeBounds<usize>>(&self, range: R) -> Option<u32> {
+		Some(Adler32::checksum(self.index(range)?))
+	}
+	/// Computes the [`Adler32`] checksum of the range.
+	#[track_caller]
+	#[inline]
+	pub fn adler32<R: ops::RangeBounds<usize>>(&self, range: R) -> u32 {
+		match self.try_adler32(range) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
@@ -0,0 +1,152 @@
+use core::{iter, ops};
+use core::marker::PhantomData;
+use super::*;
+use crate::zip_records::record_count;
+
+/// Iterator over one field of every record, see [`DataView::column`].
+#[derive(Clone)]
+pub struct Column<'a, T> {
+	view: &'a DataView,
+	stride: usize,
+	index: usize,
+	len: usize,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+/// Iterator over the bytes of one field of every record, see [`DataView::column_bytes`].
+#[derive(Clone)]
+pub struct ColumnBytes<'a> {
+	view: &'a DataView,
+	size: usize,
+	stride: usize,
+	index: usize,
+	len: usize,
+}
+
+//----------------------------------------------------------------
+
+/// Structure of arrays access over an array of records.
+impl DataView {
+	/// Iterates over the field at `offset` of every record `stride` bytes apart.
+	///
+	/// The values are read by value and may be unaligned.
+	/// Iteration stops at the last record whose field is in bounds.
+	///
+	/// ```
+	/// #[derive(dataview::Pod)]
+	/// #[repr(C)]
+	/// struct Particle {
+	/// 	pos: [f32; 2],
+	/// 	mass: f32,
+	/// }
+	///
+	/// let particles = [
+	/// 	Particle { pos: [0.0, 1.0], mass: 2.0 },
+	/// 	Particle { pos: [3.0, 4.0], mass: 5.0 },
+	/// ];
+	/// let view = dataview::DataView::from(&particles);
+	///
+	/// let stride = std::mem::size_of::<Particle>();
+	/// let total: f32 = view.column::<f32>(dataview::offset_of!(Particle.mass), stride).sum();
+	/// assert_eq!(total, 7.0);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the stride is zero.
+	#[track_caller]
+	#[inline]
+	pub fn column<T: AnyBitPattern>(&self, offset: usize, stride: usize) -> Column<'_, T> {
+		let view = self.index(offset..).unwrap_or(&self[..0]);
+		let len = record_count(view.len(), mem::size_of::<T>(), stride);
+		Column { view, stride, index: 0, len, _phantom: PhantomData }
+	}
+	/// Iterates over the bytes in `span` of every record `stride` bytes apart.
+	///
+	/// # Panics
+	///
+	/// Panics if the stride is zero or the span does not fit in a record.
+	#[track_caller]
+	#[inline]
+	pub fn column_bytes(&self, span: ops::Range<usize>, stride: usize) -> ColumnBytes<'_> {
+		assert!(span.start <= span.end && span.end <= stride, "span does not fit in the record");
+		let view = self.index(span.start..).unwrap_or(&self[..0]);
+		let size = span.end - span.start;
+		let len = record_count(view.len(), size, stride);
+		ColumnBytes { view, size, stride, index: 0, len }
+	}
+	/// Splits the records into a column per span.
+	///
+	/// The spans can be computed with [`span_of!`](crate::span_of).
+	///
+	/// ```
+	/// #[derive(dataview::Pod)]
+	/// #[repr(C)]
+	/// struct Entry {
+	/// 	id: u16,
+	/// 	tag: [u8; 2],
+	/// }
+	///
+	/// let entries = [Entry { id: 1, tag: *b"ab" }, Entry { id: 2, tag: *b"cd" }];
+	/// let view = dataview::DataView::from(&entries);
+	///
+	/// let [ids, tags] = view.split_columns(4, [
+	/// 	dataview::span_of!(Entry.id),
+	/// 	dataview::span_of!(Entry.tag),
+	/// ]);
+	/// assert!(ids.map(|id| id.read::<u16>(0)).eq([1, 2]));
+	/// assert!(tags.map(|tag| tag.as_ref()).eq([b"ab", b"cd"]));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the stride is zero or any span does not fit in a record.
+	#[track_caller]
+	#[inline]
+	pub fn split_columns<const N: usize>(&self, stride: usize, spans: [ops::Range<usize>; N]) -> [ColumnBytes<'_>; N] {
+		spans.map(|span| self.column_bytes(span, stride))
+	}
+}
+
+impl<'a, T: AnyBitPattern> Iterator for Column<'a, T> {
+	type Item = T;
+	#[inline]
+	fn next(&mut self) -> Option<T> {
+		if self.index >= self.len {
+			return None;
+		}
+		let offset = self.index * self.stride;
+		self.index += 1;
+		unsafe { Some(self.view.read_unchecked(offset)) }
+	}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len - self.index;
+		(len, Some(len))
+	}
+}
+
+impl<'a, T: AnyBitPattern> ExactSizeIterator for Column<'a, T> {}
+impl<'a, T: AnyBitPattern> iter::FusedIterator for Column<'a, T> {}
+
+impl<'a> Iterator for ColumnBytes<'a> {
+	type Item = &'a DataView;
+	#[inline]
+	fn next(&mut self) -> Option<&'a DataView> {
+		if self.index >= self.len {
+			return None;
+		}
+		let offset = self.index * self.stride;
+		self.index += 1;
+		let bytes = unsafe { self.view.as_ref().get_unchecked(offset..offset + self.size) };
+		Some(DataView::from(bytes))
+	}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len - self.index;
+		(len, Some(len))
+	}
+}
+
+impl<'a> ExactSizeIterator for ColumnBytes<'a> {}
+impl<'a> iter::FusedIterator for ColumnBytes<'a> {}
@@ -0,0 +1,112 @@
+//! Compiletime layout reporting.
+//!
+//! The macros in this module deliberately fail to compile.
+//! The value of interest is rendered in the resulting type mismatch error, eg. `found struct Size<Foo, 4>`.
+
+use core::marker::PhantomData;
+
+pub struct Value<const N: usize>;
+pub struct Size<T: ?Sized, const N: usize>(pub PhantomData<T>);
+pub struct Align<T: ?Sized, const N: usize>(pub PhantomData<T>);
+pub struct Offset<F, const N: usize>(pub PhantomData<F>);
+
+/// Reports the evaluated value of a constant expression.
+///
+/// The result is a compiletime error: `expected (), found Value<N>` where `N` is the value of the constant expression.
+///
+/// ```compile_fail
+/// dataview::const_report!(3 * 4);
+/// ```
+#[macro_export]
+macro_rules! const_report {
+	($e:expr) => {
+		const _: () = {
+			let _: () = $crate::const_report::Value::<{ $e }>;
+		};
+	};
+}
+
+/// Reports the size of a type.
+///
+/// The result is a compiletime error: `expected (), found Size<T, N>` where `N` is the size of the type `T`.
+///
+/// ```compile_fail
+/// struct Foo {
+/// 	field1: i8,
+/// 	field2: u16,
+/// }
+///
+/// dataview::report_size!(Foo);
+/// ```
+#[macro_export]
+macro_rules! report_size {
+	($ty:ty) => {
+		const _: () = {
+			let _: () = $crate::const_report::Size::<$ty, { ::core::mem::size_of::<$ty>() }>(::core::marker::PhantomData);
+		};
+	};
+}
+
+/// Reports the alignment of a type.
+///
+/// The result is a compiletime error: `expected (), found Align<T, N>` where `N` is the alignment of the type `T`.
+///
+/// ```compile_fail
+/// dataview::report_align!(u64);
+/// ```
+#[macro_export]
+macro_rules! report_align {
+	($ty:ty) => {
+		const _: () = {
+			let _: () = $crate::const_report::Align::<$ty, { ::core::mem::align_of::<$ty>() }>(::core::marker::PhantomData);
+		};
+	};
+}
+
+/// Reports the offsets of the listed fields of a struct.
+///
+/// The result is a compiletime error per field: `expected (), found Offset<field, N>` where `N` is the offset of the field.
+///
+/// ```compile_fail
+/// #[repr(C)]
+/// struct Foo {
+/// 	field1: i8,
+/// 	field2: u16,
+/// }
+///
+/// dataview::report_offsets!(Foo { field1, field2 });
+/// ```
+#[macro_export]
+macro_rules! report_offsets {
+	($ty:ty { $($field:ident),* $(,)? }) => {
+		$(
+			const _: () = {
+				#[allow(non_camel_case_types)]
+				struct $field;
+				let _: () = $crate::const_report::Offset::<$field, { $crate::offset_of!($ty.$field) }>(::core::marker::PhantomData);
+			};
+		)*
+	};
+}
+
+/// Reveals the evaluated value of a constant expression.
+///
+/// The result is a compiletime error: `expected an array with a fixed size of 0 elements, found one with N elements` where `N` is the value of the constant expression.
+///
+/// Prefer [`const_report!`] which renders any value including zero.
+///
+/// ```compile_fail
+/// struct Foo {
+/// 	field1: i8,
+/// 	field2: u16,
+/// }
+///
+/// dataview::reveal_const!(std::mem::size_of::<Foo>());
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! reveal_const {
+	($e:expr) => {
+		const _: [(); 0] = [(); $e];
+	};
+}
@@ -0,0 +1,117 @@
+use core::{fmt, ops};
+use alloc::borrow::Cow;
+use super::*;
+
+/// Copy-on-write data view.
+///
+/// Wraps either a borrowed [`DataView`] or an owned [`DataViewBuf`].
+/// The borrowed bytes are copied the first time the view is mutably dereferenced.
+///
+/// ```
+/// use dataview::{CowView, DataView};
+///
+/// let value = [1u32, 2, 3];
+/// let mut view = CowView::from(DataView::from(&value));
+/// assert_eq!(view.read::<u32>(4), 2);
+/// assert!(view.is_borrowed());
+///
+/// view.write(4, &5u32);
+/// assert!(view.is_owned());
+/// assert_eq!(view.read::<u32>(4), 5);
+/// assert_eq!(value, [1, 2, 3]);
+/// ```
+#[derive(Clone)]
+pub struct CowView<'a> {
+	inner: Cow<'a, DataView>,
+}
+
+impl<'a> CowView<'a> {
+	/// Wraps a borrowed view.
+	#[inline]
+	pub const fn borrowed(view: &'a DataView) -> CowView<'a> {
+		CowView { inner: Cow::Borrowed(view) }
+	}
+	/// Wraps an owned buffer.
+	#[inline]
+	pub const fn owned(buf: DataViewBuf) -> CowView<'a> {
+		CowView { inner: Cow::Owned(buf) }
+	}
+	/// Returns true if the bytes have not been copied.
+	#[inline]
+	pub fn is_borrowed(&self) -> bool {
+		matches!(self.inner, Cow::Borrowed(_))
+	}
+	/// Returns true if the bytes are owned.
+	#[inline]
+	pub fn is_owned(&self) -> bool {
+		matches!(self.inner, Cow::Owned(_))
+	}
+	/// Returns the owned buffer, copying the borrowed bytes if necessary.
+	#[inline]
+	pub fn to_mut(&mut self) -> &mut DataViewBuf {
+		self.inner.to_mut()
+	}
+	/// Returns the owned buffer, copying the borrowed bytes if necessary.
+	#[inline]
+	pub fn into_owned(self) -> DataViewBuf {
+		self.inner.into_owned()
+	}
+	/// Returns the wrapped `Cow`.
+	#[inline]
+	pub fn into_inner(self) -> Cow<'a, DataView> {
+		self.inner
+	}
+}
+
+impl<'a> ops::Deref for CowView<'a> {
+	type Target = DataView;
+	#[inline]
+	fn deref(&self) -> &DataView {
+		&self.inner
+	}
+}
+impl<'a> ops::DerefMut for CowView<'a> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut DataView {
+		self.inner.to_mut()
+	}
+}
+
+impl<'a> AsRef<[u8]> for CowView<'a> {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		(*self.inner).as_ref()
+	}
+}
+
+impl<'a> From<&'a DataView> for CowView<'a> {
+	#[inline]
+	fn from(view: &'a DataView) -> CowView<'a> {
+		CowView::borrowed(view)
+	}
+}
+impl<'a> From<DataViewBuf> for CowView<'a> {
+	#[inline]
+	fn from(buf: DataViewBuf) -> CowView<'a> {
+		CowView::owned(buf)
+	}
+}
+impl<'a> From<Cow<'a, DataView>> for CowView<'a> {
+	#[inline]
+	fn from(inner: Cow<'a, DataView>) -> CowView<'a> {
+		CowView { inner }
+	}
+}
+impl<'a> From<CowView<'a>> for Cow<'a, DataView> {
+	#[inline]
+	fn from(view: CowView<'a>) -> Cow<'a, DataView> {
+		view.inner
+	}
+}
+
+impl<'a> fmt::Debug for CowView<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let kind = if self.is_owned() { "Owned" } else { "Borrowed" };
+		f.debug_tuple(kind).field(&(*self.inner).as_ref()).finish()
+	}
+}
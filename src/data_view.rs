@@ -1,6 +1,22 @@
-use core::{mem, ops, ptr, slice};
+use core::{array, cmp, hash, mem, ops, ptr, slice};
 use super::*;
 
+/// Operations on at least this many bytes are instrumented with the `tracing` feature.
+#[cfg(feature = "tracing")]
+const BULK_THRESHOLD: usize = 4096;
+
+// Enters a tracing span for the rest of the scope if the operation is large enough
+macro_rules! bulk_span {
+	($name:literal, $offset:expr, $len:expr) => {
+		#[cfg(feature = "tracing")]
+		let _span = if $len >= BULK_THRESHOLD {
+			Some(::tracing::trace_span!(concat!("dataview::", $name), offset = $offset, len = $len).entered())
+		} else {
+			None
+		};
+	};
+}
+
 /// Read and write data to and from the underlying byte buffer.
 ///
 /// # Operations
@@ -15,6 +31,10 @@ use super::*;
 ///
 ///   Reads a (potentially unaligned) value out of the view into the dest argument.
 ///
+/// * `read_uninit(offset, dest)`
+///
+///   Reads a (potentially unaligned) value out of the view into uninitialized memory.
+///
 /// * `get(offset)`
 ///
 ///   Gets a reference to the data given the offset.
@@ -25,6 +45,11 @@ use super::*;
 ///   Gets a mutable reference to the data given the offset.
 ///   Errors if the final pointer is misaligned for the given type.
 ///
+/// * `get_many_mut(offsets)`
+///
+///   Gets many mutable references to the data given the offsets.
+///   Errors if any final pointer is misaligned for the given type or if any of the references overlap.
+///
 /// * `slice(offset, len)`
 ///
 ///   Gets a slice to the data given the offset and len.
@@ -39,6 +64,20 @@ use super::*;
 ///
 ///   Writes a value to the view at the given offset.
 ///
+/// * `write_stream(offset, value)`
+///
+///   Writes a value to the view at the given offset bypassing the cache where supported.
+///
+/// * `read_volatile(offset)`
+///
+///   Reads a value out of the view with a volatile load.
+///   Errors if the final pointer is misaligned for the given type.
+///
+/// * `write_volatile(offset, value)`
+///
+///   Writes a value to the view with a volatile store.
+///   Errors if the final pointer is misaligned for the given type.
+///
 /// # Panics
 ///
 /// *Panicking* methods have no prefix or suffix. They invoke the *Try* methods and panic if they return `None`.
@@ -48,6 +87,17 @@ use super::*;
 ///
 /// The relevant methods are annotated with `#[track_caller]` providing a useful location where the error happened.
 ///
+/// With the `invalid_offset_hook` feature enabled the methods call a user defined function instead of panicking.
+/// This avoids pulling in the panic formatting machinery on targets where code size matters.
+/// Every binary linking the crate must define the function, see `examples/invalid_offset_hook.rs`:
+///
+/// ```ignore
+/// #[no_mangle]
+/// fn __dataview_invalid_offset() -> ! {
+/// 	loop {}
+/// }
+/// ```
+///
 /// # Safety
 ///
 /// The *Unchecked* methods have the `_unchecked` suffix and simply assume the offset is correct.
@@ -60,9 +110,16 @@ pub struct DataView {
 }
 
 impl DataView {
+	/// Returns a data view into the bytes.
+	///
+	/// Unlike [`from`](DataView::from) this is a const fn.
+	#[inline]
+	pub const fn from_bytes(bytes: &[u8]) -> &DataView {
+		unsafe { mem::transmute(bytes) }
+	}
 	/// Returns a data view into the object's memory.
 	#[inline]
-	pub fn from<T: ?Sized + Pod>(v: &T) -> &DataView {
+	pub fn from<T: ?Sized + AsBytes>(v: &T) -> &DataView {
 		unsafe { mem::transmute(bytes(v)) }
 	}
 	/// Returns a mutable data view into the object's memory.
@@ -93,6 +150,26 @@ impl DataView {
 	pub const fn len(&self) -> usize {
 		self.bytes.len()
 	}
+	/// Returns `true` if the instance has a length of 0.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.bytes.is_empty()
+	}
+	// Bounds check written as plain comparisons, the constant size folds away for fixed size reads and writes.
+	// Unlike constructing a range this does not overflow for offsets near `usize::MAX`.
+	#[inline(always)]
+	const fn in_bounds(&self, offset: usize, size: usize) -> bool {
+		offset <= self.bytes.len() && size <= self.bytes.len() - offset
+	}
+	// The element count is compared against the elements remaining after the offset,
+	// the division by a constant size is cheaper than an overflow checked multiplication and range.
+	#[inline(always)]
+	pub(crate) fn in_bounds_slice<T>(&self, offset: usize, len: usize) -> bool {
+		offset <= self.bytes.len() && match mem::size_of::<T>() {
+			0 => true,
+			size => len <= (self.bytes.len() - offset) / size,
+		}
+	}
 	/// Returns the number of elements that would fit a slice starting at the given offset.
 	#[inline]
 	pub const fn tail_len<T>(&self, offset: usize) -> usize {
@@ -105,27 +182,41 @@ impl DataView {
 /// Reads a (potentially unaligned) value from the view.
 impl DataView {
 	/// Reads a (potentially unaligned) value from the view.
+	///
+	/// This is a const fn and can parse embedded data at compiletime:
+	///
+	/// ```
+	/// use dataview::DataView;
+	///
+	/// const MAGIC: Option<u32> = DataView::from_bytes(b"\x7fELF\x02\x01").try_read(0);
+	/// assert_eq!(MAGIC, Some(u32::from_ne_bytes(*b"\x7fELF")));
+	/// ```
 	#[inline]
-	pub fn try_read<T: Pod>(&self, offset: usize) -> Option<T> {
-		let index = offset..offset + mem::size_of::<T>();
-		let bytes = self.bytes.get(index)?;
+	pub const fn try_read<T: AnyBitPattern>(&self, offset: usize) -> Option<T> {
+		if !self.in_bounds(offset, mem::size_of::<T>()) {
+			return None;
+		}
 		unsafe {
-			let src = bytes.as_ptr() as *const T;
+			let src = self.bytes.as_ptr().add(offset) as *const T;
 			Some(ptr::read_unaligned(src))
 		}
 	}
 	/// Reads a (potentially unaligned) value from the view.
 	#[track_caller]
 	#[inline]
-	pub fn read<T: Pod>(&self, offset: usize) -> T {
+	pub fn read<T: AnyBitPattern>(&self, offset: usize) -> T {
 		match self.try_read(offset) {
 			Some(value) => value,
 			None => invalid_offset(),
 		}
 	}
 	/// Reads a (potentially unaligned) value from the view.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_read`](Self::try_read) would return `None`.
 	#[inline]
-	pub unsafe fn read_unchecked<T: Pod>(&self, offset: usize) -> T {
+	pub unsafe fn read_unchecked<T: AnyBitPattern>(&self, offset: usize) -> T {
 		let index = offset..offset + mem::size_of::<T>();
 		let bytes = self.bytes.get_unchecked(index);
 		let src = bytes.as_ptr() as *const T;
@@ -139,12 +230,13 @@ impl DataView {
 impl DataView {
 	/// Reads a (potentially unaligned) value from the view into the destination.
 	#[inline]
-	pub fn try_read_into<T: ?Sized + Pod>(&self, offset: usize, dest: &mut T) -> Option<()> {
+	pub fn try_read_into<T: ?Sized + AnyBitPattern>(&self, offset: usize, dest: &mut T) -> Option<()> {
 		let index = offset..offset + mem::size_of_val(dest);
 		let bytes = self.bytes.get(index)?;
+		bulk_span!("read_into", offset, bytes.len());
 		unsafe {
 			let src = bytes.as_ptr();
-			let dst = bytes_mut(dest).as_mut_ptr();
+			let dst = dest as *mut T as *mut u8;
 			ptr::copy_nonoverlapping(src, dst, bytes.len());
 			Some(())
 		}
@@ -152,30 +244,100 @@ impl DataView {
 	/// Reads a (potentially unaligned) value from the view into the destination.
 	#[track_caller]
 	#[inline]
-	pub fn read_into<T: ?Sized + Pod>(&self, offset: usize, dest: &mut T) {
+	pub fn read_into<T: ?Sized + AnyBitPattern>(&self, offset: usize, dest: &mut T) {
 		match self.try_read_into(offset, dest) {
 			Some(()) => (),
 			None => invalid_offset(),
 		}
 	}
 	/// Reads a (potentially unaligned) value from the view into the destination.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_read_into`](Self::try_read_into) would return `None`.
 	#[inline]
-	pub unsafe fn read_into_unchecked<T: ?Sized + Pod>(&self, offset: usize, dest: &mut T) {
+	pub unsafe fn read_into_unchecked<T: ?Sized + AnyBitPattern>(&self, offset: usize, dest: &mut T) {
 		let index = offset..offset + mem::size_of_val(dest);
 		let bytes = self.bytes.get_unchecked(index);
 		let src = bytes.as_ptr();
-		let dst = bytes_mut(dest).as_mut_ptr();
+		let dst = dest as *mut T as *mut u8;
 		ptr::copy_nonoverlapping(src, dst, bytes.len());
 	}
 }
 
 //----------------------------------------------------------------
 
+/// Reads a (potentially unaligned) value from the view into uninitialized memory.
+impl DataView {
+	/// Reads a (potentially unaligned) value from the view into uninitialized memory.
+	///
+	/// Returns a reference to the now initialized destination.
+	#[inline]
+	pub fn try_read_uninit<'a, T: AnyBitPattern>(&self, offset: usize, dest: &'a mut mem::MaybeUninit<T>) -> Option<&'a mut T> {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.bytes.get(index)?;
+		unsafe {
+			ptr::copy_nonoverlapping(bytes.as_ptr(), dest.as_mut_ptr() as *mut u8, bytes.len());
+			Some(&mut *dest.as_mut_ptr())
+		}
+	}
+	/// Reads a (potentially unaligned) value from the view into uninitialized memory.
+	///
+	/// Returns a reference to the now initialized destination.
+	#[track_caller]
+	#[inline]
+	pub fn read_uninit<'a, T: AnyBitPattern>(&self, offset: usize, dest: &'a mut mem::MaybeUninit<T>) -> &'a mut T {
+		match self.try_read_uninit(offset, dest) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Reads a (potentially unaligned) value from the view into uninitialized memory.
+	///
+	/// Returns a reference to the now initialized destination.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_read_uninit`](Self::try_read_uninit) would return `None`.
+	#[inline]
+	pub unsafe fn read_uninit_unchecked<'a, T: AnyBitPattern>(&self, offset: usize, dest: &'a mut mem::MaybeUninit<T>) -> &'a mut T {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.bytes.get_unchecked(index);
+		ptr::copy_nonoverlapping(bytes.as_ptr(), dest.as_mut_ptr() as *mut u8, bytes.len());
+		&mut *dest.as_mut_ptr()
+	}
+	/// Reads (potentially unaligned) elements from the view into an uninitialized slice.
+	///
+	/// Returns a reference to the now initialized destination.
+	#[inline]
+	pub fn try_read_uninit_slice<'a, T: AnyBitPattern>(&self, offset: usize, dest: &'a mut [mem::MaybeUninit<T>]) -> Option<&'a mut [T]> {
+		let index = offset..offset + mem::size_of_val(dest);
+		let bytes = self.bytes.get(index)?;
+		unsafe {
+			ptr::copy_nonoverlapping(bytes.as_ptr(), dest.as_mut_ptr() as *mut u8, bytes.len());
+			Some(slice::from_raw_parts_mut(dest.as_mut_ptr() as *mut T, dest.len()))
+		}
+	}
+	/// Reads (potentially unaligned) elements from the view into an uninitialized slice.
+	///
+	/// Returns a reference to the now initialized destination.
+	#[track_caller]
+	#[inline]
+	pub fn read_uninit_slice<'a, T: AnyBitPattern>(&self, offset: usize, dest: &'a mut [mem::MaybeUninit<T>]) -> &'a mut [T] {
+		match self.try_read_uninit_slice(offset, dest) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
 /// Gets an aligned reference into the view.
 impl DataView {
 	/// Gets an aligned reference into the view.
 	#[inline]
-	pub fn try_get<T: Pod>(&self, offset: usize) -> Option<&T> {
+	pub fn try_get<T: AnyBitPattern>(&self, offset: usize) -> Option<&T> {
 		let index = offset..offset + mem::size_of::<T>();
 		let bytes = self.bytes.get(index)?;
 		let unaligned_ptr = bytes.as_ptr() as *const T;
@@ -189,15 +351,19 @@ impl DataView {
 	/// Gets an aligned reference into the view.
 	#[track_caller]
 	#[inline]
-	pub fn get<T: Pod>(&self, offset: usize) -> &T {
+	pub fn get<T: AnyBitPattern>(&self, offset: usize) -> &T {
 		match self.try_get(offset) {
 			Some(value) => value,
 			None => invalid_offset(),
 		}
 	}
 	/// Gets an aligned reference into the view.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_get`](Self::try_get) would return `None`.
 	#[inline]
-	pub unsafe fn get_unchecked<T: Pod>(&self, offset: usize) -> &T {
+	pub unsafe fn get_unchecked<T: AnyBitPattern>(&self, offset: usize) -> &T {
 		let index = offset..offset + mem::size_of::<T>();
 		let bytes = self.bytes.get_unchecked(index);
 		&*(bytes.as_ptr() as *const T)
@@ -231,12 +397,43 @@ impl DataView {
 		}
 	}
 	/// Gets an aligned mutable reference into the view.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_get_mut`](Self::try_get_mut) would return `None`.
 	#[inline]
 	pub unsafe fn get_unchecked_mut<T: Pod>(&mut self, offset: usize) -> &mut T {
 		let index = offset..offset + mem::size_of::<T>();
 		let bytes = self.bytes.get_unchecked_mut(index);
 		&mut *(bytes.as_mut_ptr() as *mut T)
 	}
+	/// Gets many aligned mutable references into the view at once.
+	///
+	/// Errors if any of the references overlap.
+	#[inline]
+	pub fn try_get_many_mut<T: Pod, const N: usize>(&mut self, offsets: [usize; N]) -> Option<[&mut T; N]> {
+		let size = mem::size_of::<T>();
+		for (i, &offset) in offsets.iter().enumerate() {
+			if !self.in_bounds(offset, size) || !is_aligned(self.bytes.as_ptr().wrapping_add(offset) as *const T) {
+				return None;
+			}
+			// Both offsets are in bounds, these additions cannot overflow
+			if offsets[..i].iter().any(|&other| offset < other + size && other < offset + size) {
+				return None;
+			}
+		}
+		let ptr = self.bytes.as_mut_ptr();
+		Some(array::from_fn(|i| unsafe { &mut *(ptr.add(offsets[i]) as *mut T) }))
+	}
+	/// Gets many aligned mutable references into the view at once.
+	#[track_caller]
+	#[inline]
+	pub fn get_many_mut<T: Pod, const N: usize>(&mut self, offsets: [usize; N]) -> [&mut T; N] {
+		match self.try_get_many_mut(offsets) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
 }
 
 //----------------------------------------------------------------
@@ -245,10 +442,11 @@ impl DataView {
 impl DataView {
 	/// Gets an aligned slice into the view.
 	#[inline]
-	pub fn try_slice<T: Pod>(&self, offset: usize, len: usize) -> Option<&[T]> {
-		let index = offset..offset + usize::checked_mul(len, mem::size_of::<T>())?;
-		let bytes = self.bytes.get(index)?;
-		let unaligned_ptr = bytes.as_ptr() as *const T;
+	pub fn try_slice<T: AnyBitPattern>(&self, offset: usize, len: usize) -> Option<&[T]> {
+		if !self.in_bounds_slice::<T>(offset, len) {
+			return None;
+		}
+		let unaligned_ptr = self.bytes.as_ptr().wrapping_add(offset) as *const T;
 		if !is_aligned(unaligned_ptr) {
 			return None;
 		}
@@ -259,15 +457,19 @@ impl DataView {
 	/// Gets an aligned slice into the view.
 	#[track_caller]
 	#[inline]
-	pub fn slice<T: Pod>(&self, offset: usize, len: usize) -> &[T] {
+	pub fn slice<T: AnyBitPattern>(&self, offset: usize, len: usize) -> &[T] {
 		match self.try_slice(offset, len) {
 			Some(value) => value,
 			None => invalid_offset(),
 		}
 	}
 	/// Gets an aligned slice into the view.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_slice`](Self::try_slice) would return `None`.
 	#[inline]
-	pub unsafe fn slice_unchecked<T: Pod>(&self, offset: usize, len: usize) -> &[T] {
+	pub unsafe fn slice_unchecked<T: AnyBitPattern>(&self, offset: usize, len: usize) -> &[T] {
 		let index = offset..offset + len * mem::size_of::<T>();
 		let bytes = self.bytes.get_unchecked(index);
 		slice::from_raw_parts(bytes.as_ptr() as *const T, len)
@@ -281,9 +483,10 @@ impl DataView {
 	/// Gets an aligned mutable slice into the view.
 	#[inline]
 	pub fn try_slice_mut<T: Pod>(&mut self, offset: usize, len: usize) -> Option<&mut [T]> {
-		let index = offset..offset + usize::checked_mul(len, mem::size_of::<T>())?;
-		let bytes = self.bytes.get_mut(index)?;
-		let unaligned_ptr = bytes.as_mut_ptr() as *mut T;
+		if !self.in_bounds_slice::<T>(offset, len) {
+			return None;
+		}
+		let unaligned_ptr = self.bytes.as_mut_ptr().wrapping_add(offset) as *mut T;
 		if !is_aligned(unaligned_ptr) {
 			return None;
 		}
@@ -301,6 +504,10 @@ impl DataView {
 		}
 	}
 	/// Gets an aligned mutable slice into the view.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_slice_mut`](Self::try_slice_mut) would return `None`.
 	#[inline]
 	pub unsafe fn slice_unchecked_mut<T: Pod>(&mut self, offset: usize, len: usize) -> &mut [T] {
 		let index = offset..offset + len * mem::size_of::<T>();
@@ -315,24 +522,34 @@ impl DataView {
 impl DataView {
 	/// Writes a value into the view.
 	#[inline]
-	pub fn try_write<T: ?Sized + Pod>(&mut self, offset: usize, value: &T) -> Option<()> {
-		let index = offset..offset + mem::size_of_val(value);
-		let bytes = self.bytes.get_mut(index)?;
-		bytes.copy_from_slice(crate::bytes(value));
-		Some(())
+	pub fn try_write<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) -> Option<()> {
+		let len = mem::size_of_val(value);
+		if !self.in_bounds(offset, len) {
+			return None;
+		}
+		bulk_span!("write", offset, len);
+		unsafe {
+			let dst = self.bytes.as_mut_ptr().add(offset);
+			ptr::copy_nonoverlapping(crate::bytes(value).as_ptr(), dst, len);
+			Some(())
+		}
 	}
 	/// Writes a value into the view.
 	#[track_caller]
 	#[inline]
-	pub fn write<T: ?Sized + Pod>(&mut self, offset: usize, value: &T) {
+	pub fn write<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) {
 		match self.try_write(offset, value) {
 			Some(()) => (),
 			None => invalid_offset(),
 		}
 	}
 	/// Writes a value into the view.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_write`](Self::try_write) would return `None`.
 	#[inline]
-	pub unsafe fn write_unchecked<T: ?Sized + Pod>(&mut self, offset: usize, value: &T) {
+	pub unsafe fn write_unchecked<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) {
 		let index = offset..offset + mem::size_of_val(value);
 		let bytes = self.bytes.get_unchecked_mut(index);
 		ptr::copy_nonoverlapping(crate::bytes(value).as_ptr(), bytes.as_mut_ptr(), bytes.len());
@@ -341,6 +558,149 @@ impl DataView {
 
 //----------------------------------------------------------------
 
+/// Writes a value into the view using non-temporal stores.
+///
+/// Intended for large writes which should not pollute the cache, such as filling frame buffers.
+/// On x86_64 the bulk of the data is written with streaming stores followed by a store fence.
+/// On other targets this is equivalent to a regular write.
+impl DataView {
+	/// Writes a value into the view using non-temporal stores.
+	#[inline]
+	pub fn try_write_stream<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) -> Option<()> {
+		let index = offset..offset + mem::size_of_val(value);
+		let bytes = self.bytes.get_mut(index)?;
+		bulk_span!("write_stream", offset, bytes.len());
+		unsafe {
+			copy_stream(crate::bytes(value).as_ptr(), bytes.as_mut_ptr(), bytes.len());
+			Some(())
+		}
+	}
+	/// Writes a value into the view using non-temporal stores.
+	#[track_caller]
+	#[inline]
+	pub fn write_stream<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) {
+		match self.try_write_stream(offset, value) {
+			Some(()) => (),
+			None => invalid_offset(),
+		}
+	}
+	/// Writes a value into the view using non-temporal stores.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_write_stream`](Self::try_write_stream) would return `None`.
+	#[inline]
+	pub unsafe fn write_stream_unchecked<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) {
+		let index = offset..offset + mem::size_of_val(value);
+		let bytes = self.bytes.get_unchecked_mut(index);
+		copy_stream(crate::bytes(value).as_ptr(), bytes.as_mut_ptr(), bytes.len());
+	}
+}
+
+#[cfg(target_arch = "x86_64")]
+unsafe fn copy_stream(src: *const u8, dst: *mut u8, len: usize) {
+	use core::arch::x86_64::*;
+	// Copy the head regularly so the streaming stores are aligned
+	let head = cmp::min(dst.align_offset(16), len);
+	ptr::copy_nonoverlapping(src, dst, head);
+	let mut i = head;
+	while len - i >= 16 {
+		let chunk = _mm_loadu_si128(src.add(i) as *const __m128i);
+		_mm_stream_si128(dst.add(i) as *mut __m128i, chunk);
+		i += 16;
+	}
+	ptr::copy_nonoverlapping(src.add(i), dst.add(i), len - i);
+	// Streaming stores are weakly ordered
+	_mm_sfence();
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+#[inline]
+unsafe fn copy_stream(src: *const u8, dst: *mut u8, len: usize) {
+	ptr::copy_nonoverlapping(src, dst, len);
+}
+
+//----------------------------------------------------------------
+
+/// Reads an aligned value from the view using a volatile load.
+impl DataView {
+	/// Reads an aligned value from the view using a volatile load.
+	#[inline]
+	pub fn try_read_volatile<T: AnyBitPattern>(&self, offset: usize) -> Option<T> {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.bytes.get(index)?;
+		let unaligned_ptr = bytes.as_ptr() as *const T;
+		if !is_aligned(unaligned_ptr) {
+			return None;
+		}
+		unsafe {
+			Some(ptr::read_volatile(unaligned_ptr))
+		}
+	}
+	/// Reads an aligned value from the view using a volatile load.
+	#[track_caller]
+	#[inline]
+	pub fn read_volatile<T: AnyBitPattern>(&self, offset: usize) -> T {
+		match self.try_read_volatile(offset) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Reads an aligned value from the view using a volatile load.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_read_volatile`](Self::try_read_volatile) would return `None`.
+	#[inline]
+	pub unsafe fn read_volatile_unchecked<T: AnyBitPattern>(&self, offset: usize) -> T {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.bytes.get_unchecked(index);
+		ptr::read_volatile(bytes.as_ptr() as *const T)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Writes an aligned value into the view using a volatile store.
+impl DataView {
+	/// Writes an aligned value into the view using a volatile store.
+	#[inline]
+	pub fn try_write_volatile<T: AsBytes>(&mut self, offset: usize, value: &T) -> Option<()> {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.bytes.get_mut(index)?;
+		let unaligned_ptr = bytes.as_mut_ptr() as *mut T;
+		if !is_aligned(unaligned_ptr) {
+			return None;
+		}
+		unsafe {
+			ptr::write_volatile(unaligned_ptr, ptr::read(value));
+			Some(())
+		}
+	}
+	/// Writes an aligned value into the view using a volatile store.
+	#[track_caller]
+	#[inline]
+	pub fn write_volatile<T: AsBytes>(&mut self, offset: usize, value: &T) {
+		match self.try_write_volatile(offset, value) {
+			Some(()) => (),
+			None => invalid_offset(),
+		}
+	}
+	/// Writes an aligned value into the view using a volatile store.
+	///
+	/// # Safety
+	///
+	/// Undefined behavior if [`try_write_volatile`](Self::try_write_volatile) would return `None`.
+	#[inline]
+	pub unsafe fn write_volatile_unchecked<T: AsBytes>(&mut self, offset: usize, value: &T) {
+		let index = offset..offset + mem::size_of::<T>();
+		let bytes = self.bytes.get_unchecked_mut(index);
+		ptr::write_volatile(bytes.as_mut_ptr() as *mut T, ptr::read(value));
+	}
+}
+
+//----------------------------------------------------------------
+
 impl DataView {
 	/// Index the DataView creating a subview.
 	#[inline]
@@ -378,6 +738,34 @@ impl DataView {
 
 //----------------------------------------------------------------
 
+/// Feeds the bytes of the view into a hasher.
+impl DataView {
+	/// Feeds the bytes of the view into a hasher.
+	///
+	/// The bytes are passed to a single [`Hasher::write`](hash::Hasher::write) call, unlike hashing a byte slice the length of the view is not hashed.
+	///
+	/// Hashers are free to produce different results when the same bytes are split over multiple writes.
+	///
+	/// ```
+	/// use std::collections::hash_map::DefaultHasher;
+	/// use std::hash::Hasher;
+	///
+	/// let mut a = DefaultHasher::new();
+	/// dataview::DataView::from(&[1u8, 2, 3, 4]).hash_into(&mut a);
+	///
+	/// let mut b = DefaultHasher::new();
+	/// b.write(&[1, 2, 3, 4]);
+	///
+	/// assert_eq!(a.finish(), b.finish());
+	/// ```
+	#[inline]
+	pub fn hash_into<H: hash::Hasher>(&self, state: &mut H) {
+		state.write(&self.bytes)
+	}
+}
+
+//----------------------------------------------------------------
+
 impl<R: ops::RangeBounds<usize>> ops::Index<R> for DataView {
 	type Output = DataView;
 	#[track_caller]
@@ -402,9 +790,20 @@ impl<R: ops::RangeBounds<usize>> ops::IndexMut<R> for DataView {
 
 //----------------------------------------------------------------
 
+#[cfg(not(feature = "invalid_offset_hook"))]
 #[cold]
 #[track_caller]
 #[inline(never)]
-fn invalid_offset() -> ! {
+pub(crate) fn invalid_offset() -> ! {
 	panic!("invalid offset")
 }
+
+#[cfg(feature = "invalid_offset_hook")]
+#[cold]
+#[inline(never)]
+pub(crate) fn invalid_offset() -> ! {
+	extern "Rust" {
+		fn __dataview_invalid_offset() -> !;
+	}
+	unsafe { __dataview_invalid_offset() }
+}
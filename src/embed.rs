@@ -0,0 +1,474 @@
+use super::*;
+
+/// Embeds a file as a static array of `Pod` values.
+///
+/// ```
+/// dataview::embed! {
+/// 	/// Lookup table of little endian integers.
+/// 	pub static TABLE: [u32] = "../tests/data/table.bin";
+/// }
+///
+/// assert_eq!(TABLE.len(), 4);
+/// assert_eq!(TABLE[1], u32::from_le(2));
+/// ```
+///
+/// The syntax is `static $name: [$ty] = $path;` and defines a static of type `[$ty; N]` where `N` is the file size divided by the size of `$ty`.
+/// The path is relative to the file invoking the macro, like `include_bytes!`.
+///
+/// The static is an array of `$ty` and therefore aligned to `$ty`.
+/// The element type must implement `AnyBitPattern` and must not be zero sized.
+///
+/// It is a compile error if the file size is not a multiple of the element size.
+///
+/// The trailing bytes of a file whose size is not a multiple of the element size can be handled with a policy:
+///
+/// * `static $name: [$ty; truncate] = $path;` ignores the trailing bytes.
+/// * `static $name: [$ty; pad] = $path;` zero pads the trailing bytes into a final element.
+///
+/// ```
+/// dataview::embed! {
+/// 	static TRUNCATED: [[u8; 4]; truncate] = "../tests/data/odd.bin";
+/// }
+/// dataview::embed! {
+/// 	static PADDED: [[u8; 4]; pad] = "../tests/data/odd.bin";
+/// }
+///
+/// assert_eq!(TRUNCATED, [*b"data", *b"view"]);
+/// assert_eq!(PADDED, [*b"data", *b"view", *b"!\0\0\0"]);
+/// ```
+///
+/// The path can be resolved against a directory set by Cargo instead:
+///
+/// * `manifest_dir($path)` is relative to the directory containing the crate's `Cargo.toml`.
+/// * `out_dir($path)` is relative to the `OUT_DIR` of the crate's build script.
+///
+/// ```
+/// dataview::embed! {
+/// 	static TABLE: [u32] = manifest_dir("tests/data/table.bin");
+/// }
+///
+/// assert_eq!(TABLE.len(), 4);
+/// ```
+///
+/// Files of integers or floats stored in a specific byte order are converted to native byte order at compile time:
+///
+/// * `static $name: [$ty; le] = $path;` for files in little endian byte order.
+/// * `static $name: [$ty; be] = $path;` for files in big endian byte order.
+///
+/// ```
+/// dataview::embed! {
+/// 	static TABLE: [u32; le] = "../tests/data/table.bin";
+/// }
+///
+/// assert_eq!(TABLE, [1, 2, 3, 4]);
+/// ```
+///
+/// A single value is embedded with the syntax `static $name: $ty = $path;`.
+/// It is a compile error if the file size is not equal to the size of `$ty`:
+///
+/// ```
+/// #[derive(dataview::Pod)]
+/// #[repr(C)]
+/// struct Config {
+/// 	magic: [u8; 4],
+/// 	values: [u32; 3],
+/// }
+///
+/// dataview::embed! {
+/// 	static CONFIG: Config = "../tests/data/table.bin";
+/// }
+///
+/// assert_eq!(CONFIG.magic, [1, 0, 0, 0]);
+/// assert_eq!(CONFIG.values[2], u32::from_le(4));
+/// ```
+#[macro_export]
+macro_rules! embed {
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$($ty:tt)*] = $dir:ident($path:expr);
+	) => {
+		$crate::embed!($(#[$meta])* $vis static $name: [$($ty)*] = $crate::__embed_dir!($dir, $path););
+	};
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: $ty:ty = $dir:ident($path:expr);
+	) => {
+		$crate::embed!($(#[$meta])* $vis static $name: $ty = $crate::__embed_dir!($dir, $path););
+	};
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$ty:ty] = $path:expr;
+	) => {
+		$(#[$meta])*
+		#[allow(clippy::useless_transmute)]
+		$vis static $name: [$ty; $crate::__embed_len::<$ty>(::core::include_bytes!($path).len())] = unsafe {
+			// The length is checked to be an exact multiple of the element size
+			::core::mem::transmute(*::core::include_bytes!($path))
+		};
+	};
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$ty:ty; truncate] = $path:expr;
+	) => {
+		$crate::embed!(@policy $(#[$meta])* $vis static $name: [$ty] = $path; __embed_len_truncate);
+	};
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$ty:ty; pad] = $path:expr;
+	) => {
+		$crate::embed!(@policy $(#[$meta])* $vis static $name: [$ty] = $path; __embed_len_pad);
+	};
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$ty:ty; le] = $path:expr;
+	) => {
+		$crate::embed!(@endian $(#[$meta])* $vis static $name: [$ty] = $path; false);
+	};
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$ty:ty; be] = $path:expr;
+	) => {
+		$crate::embed!(@endian $(#[$meta])* $vis static $name: [$ty] = $path; true);
+	};
+	(
+		@endian
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$ty:ty] = $path:expr; $big_endian:literal
+	) => {
+		$(#[$meta])*
+		#[allow(clippy::useless_transmute)]
+		$vis static $name: [$ty; $crate::__embed_len_scalar::<$ty>(::core::include_bytes!($path).len())] = unsafe {
+			// The length is checked to be an exact multiple of the element size
+			::core::mem::transmute($crate::__embed_swap::<{ ::core::include_bytes!($path).len() }>(
+				*::core::include_bytes!($path), ::core::mem::size_of::<$ty>(), $big_endian))
+		};
+	};
+	(
+		@policy
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: [$ty:ty] = $path:expr; $len:ident
+	) => {
+		$(#[$meta])*
+		#[allow(clippy::useless_transmute)]
+		$vis static $name: [$ty; $crate::$len::<$ty>(::core::include_bytes!($path).len())] = unsafe {
+			// The bytes are truncated or zero padded to a multiple of the element size
+			::core::mem::transmute($crate::__embed_bytes::<{
+				$crate::$len::<$ty>(::core::include_bytes!($path).len()) * ::core::mem::size_of::<$ty>()
+			}>(::core::include_bytes!($path)))
+		};
+	};
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident: $ty:ty = $path:expr;
+	) => {
+		$(#[$meta])*
+		$vis static $name: $ty = {
+			let [value]: [$ty; $crate::__embed_value_len::<$ty>(::core::include_bytes!($path).len())] = unsafe {
+				// The length is checked to be equal to the size of the type
+				::core::mem::transmute(*::core::include_bytes!($path))
+			};
+			value
+		};
+	};
+}
+
+/// Includes a file as a `&'static DataView`.
+///
+/// ```
+/// let view: &'static dataview::DataView = dataview::include_pod!("../tests/data/table.bin", dataview::A16);
+///
+/// assert_eq!(view.len(), 16);
+/// assert_eq!(view.as_ref().as_ptr() as usize % 16, 0);
+/// assert_eq!(view.get::<u32>(4), &u32::from_le(2));
+/// ```
+///
+/// The syntax is `include_pod!($path)` or `include_pod!($path, $align)` where `$align` is one of the [`Alignment`] marker types.
+/// Without alignment the bytes are only aligned to 1 byte.
+///
+/// The path is relative to the file invoking the macro, like `include_bytes!`.
+/// Like [`embed!`] the path can be resolved with `manifest_dir($path)` or `out_dir($path)` instead.
+#[macro_export]
+macro_rules! include_pod {
+	($dir:ident($path:expr) $(, $align:ty)? $(,)?) => {
+		$crate::include_pod!($crate::__embed_dir!($dir, $path) $(, $align)?)
+	};
+	($path:expr $(,)?) => {
+		$crate::include_pod!($path, $crate::A1)
+	};
+	($path:expr, $align:ty $(,)?) => {{
+		static BYTES: $crate::Aligned<$align, [u8; ::core::include_bytes!($path).len()]> = $crate::Aligned::new(*::core::include_bytes!($path));
+		$crate::DataView::from(&BYTES.value)
+	}};
+}
+
+/// Embeds multiple files as a static [`EmbeddedDir`].
+///
+/// ```
+/// dataview::embed_files! {
+/// 	/// Read-only assets.
+/// 	pub static ASSETS = {
+/// 		"table" => "../tests/data/table.bin",
+/// 		"odd" => manifest_dir("tests/data/odd.bin"),
+/// 	};
+/// }
+///
+/// let odd = ASSETS.get("odd").unwrap();
+/// assert_eq!(odd.as_ref(), b"dataview!");
+/// assert_eq!(ASSETS.get("table").unwrap().get::<u32>(4), &u32::from_le(2));
+/// assert!(ASSETS.get("missing").is_none());
+/// ```
+///
+/// The syntax is `static $name = { $($file => $path),* };` where the paths are resolved like [`embed!`].
+///
+/// The files are concatenated into a single static byte array, every file starts aligned to [`EmbeddedDir::ALIGN`] bytes.
+#[macro_export]
+macro_rules! embed_files {
+	(
+		$(#[$meta:meta])*
+		$vis:vis static $name:ident = {
+			$($file:literal => $path:tt $(($arg:expr))?),*
+			$(,)?
+		};
+	) => {
+		$(#[$meta])*
+		$vis static $name: $crate::EmbeddedDir = {
+			const FILES: &[&[u8]] = &[$(::core::include_bytes!($crate::__embed_file_path!($path $(($arg))?))),*];
+			const NAMES: &[&str] = &[$($file),*];
+			static DATA: $crate::Aligned<$crate::A16, [u8; $crate::__embed_files_len(FILES)]> = $crate::Aligned::new($crate::__embed_files_data(FILES));
+			static ENTRIES: [$crate::EmbeddedFile; FILES.len()] = $crate::__embed_files_entries(NAMES, FILES);
+			$crate::EmbeddedDir::new(&ENTRIES, &DATA.value)
+		};
+	};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __embed_file_path {
+	($path:literal) => {
+		$path
+	};
+	($dir:ident($path:expr)) => {
+		$crate::__embed_dir!($dir, $path)
+	};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __embed_dir {
+	(manifest_dir, $path:expr) => {
+		::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", $path)
+	};
+	(out_dir, $path:expr) => {
+		::core::concat!(::core::env!("OUT_DIR"), "/", $path)
+	};
+	($dir:ident, $path:expr) => {
+		::core::compile_error!(::core::concat!("unknown embed directory `", ::core::stringify!($dir), "`, expected `manifest_dir` or `out_dir`"))
+	};
+}
+
+#[doc(hidden)]
+pub const fn __embed_len<T: AnyBitPattern>(len: usize) -> usize {
+	let size = mem::size_of::<T>();
+	if size == 0 {
+		panic!("cannot embed zero sized types");
+	}
+	if len / size * size != len {
+		panic!("embedded file size is not a multiple of the element size");
+	}
+	len / size
+}
+
+#[doc(hidden)]
+pub const fn __embed_len_truncate<T: AnyBitPattern>(len: usize) -> usize {
+	let size = mem::size_of::<T>();
+	if size == 0 {
+		panic!("cannot embed zero sized types");
+	}
+	len / size
+}
+
+#[doc(hidden)]
+pub const fn __embed_len_pad<T: AnyBitPattern>(len: usize) -> usize {
+	let size = mem::size_of::<T>();
+	if size == 0 {
+		panic!("cannot embed zero sized types");
+	}
+	len.div_ceil(size)
+}
+
+// Copies the bytes into an array of N bytes, truncating or zero padding as needed
+#[doc(hidden)]
+pub const fn __embed_bytes<const N: usize>(bytes: &[u8]) -> [u8; N] {
+	let mut array = [0u8; N];
+	let mut i = 0;
+	while i < N && i < bytes.len() {
+		array[i] = bytes[i];
+		i += 1;
+	}
+	array
+}
+
+// Primitive types which can be byte swapped
+#[doc(hidden)]
+pub trait __EmbedScalar: AnyBitPattern {}
+macro_rules! impl_embed_scalar {
+	($($ty:ty),*) => {
+		$(impl __EmbedScalar for $ty {})*
+	};
+}
+impl_embed_scalar!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[doc(hidden)]
+pub const fn __embed_len_scalar<T: __EmbedScalar>(len: usize) -> usize {
+	__embed_len::<T>(len)
+}
+
+// Reverses the bytes of every element if the byte order differs from the target
+#[doc(hidden)]
+pub const fn __embed_swap<const N: usize>(mut bytes: [u8; N], size: usize, big_endian: bool) -> [u8; N] {
+	if big_endian != cfg!(target_endian = "big") {
+		let mut start = 0;
+		while start + size <= N {
+			let mut i = 0;
+			while i < size / 2 {
+				let tmp = bytes[start + i];
+				bytes[start + i] = bytes[start + size - 1 - i];
+				bytes[start + size - 1 - i] = tmp;
+				i += 1;
+			}
+			start += size;
+		}
+	}
+	bytes
+}
+
+#[doc(hidden)]
+pub const fn __embed_value_len<T: AnyBitPattern>(len: usize) -> usize {
+	if len != mem::size_of::<T>() {
+		panic!("embedded file size does not match the size of the type");
+	}
+	1
+}
+
+//----------------------------------------------------------------
+
+/// Entry of an [`EmbeddedDir`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EmbeddedFile {
+	/// Name of the file.
+	pub name: &'static str,
+	/// Offset of the file in the data in bytes.
+	pub offset: usize,
+	/// Length of the file in bytes.
+	pub len: usize,
+}
+
+/// Read-only directory of files embedded with [`embed_files!`].
+#[derive(Copy, Clone, Debug)]
+pub struct EmbeddedDir {
+	entries: &'static [EmbeddedFile],
+	data: &'static [u8],
+}
+
+impl EmbeddedDir {
+	/// Alignment of every file in the data.
+	pub const ALIGN: usize = 16;
+
+	/// Creates a directory from its entries and data.
+	///
+	/// Returns `None` if any entry is out of bounds of the data.
+	#[inline]
+	pub const fn try_new(entries: &'static [EmbeddedFile], data: &'static [u8]) -> Option<EmbeddedDir> {
+		let mut i = 0;
+		while i < entries.len() {
+			let entry = &entries[i];
+			if entry.offset > data.len() || entry.len > data.len() - entry.offset {
+				return None;
+			}
+			i += 1;
+		}
+		Some(EmbeddedDir { entries, data })
+	}
+	/// Creates a directory from its entries and data.
+	///
+	/// Panics if any entry is out of bounds of the data.
+	#[track_caller]
+	#[inline]
+	pub const fn new(entries: &'static [EmbeddedFile], data: &'static [u8]) -> EmbeddedDir {
+		match EmbeddedDir::try_new(entries, data) {
+			Some(dir) => dir,
+			None => panic!("embedded file out of bounds"),
+		}
+	}
+	/// Returns the entries of the directory.
+	#[inline]
+	pub const fn entries(&self) -> &'static [EmbeddedFile] {
+		self.entries
+	}
+	/// Returns the concatenated data of all the files.
+	#[inline]
+	pub const fn data(&self) -> &'static DataView {
+		DataView::from_bytes(self.data)
+	}
+	/// Returns the data of a file.
+	#[inline]
+	pub fn file(&self, entry: &EmbeddedFile) -> Option<&'static DataView> {
+		let bytes = self.data.get(entry.offset..entry.offset + entry.len)?;
+		Some(DataView::from_bytes(bytes))
+	}
+	/// Looks up a file by name.
+	#[inline]
+	pub fn get(&self, name: &str) -> Option<&'static DataView> {
+		let entry = self.entries.iter().find(|entry| entry.name == name)?;
+		self.file(entry)
+	}
+	/// Iterates over the names and data of the files.
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (&'static str, &'static DataView)> {
+		let dir = *self;
+		self.entries.iter().filter_map(move |entry| Some((entry.name, dir.file(entry)?)))
+	}
+}
+
+const fn embed_file_offset(files: &[&[u8]], index: usize) -> usize {
+	let mut offset = 0;
+	let mut i = 0;
+	while i < index {
+		offset += files[i].len();
+		offset = (offset + (EmbeddedDir::ALIGN - 1)) & !(EmbeddedDir::ALIGN - 1);
+		i += 1;
+	}
+	offset
+}
+
+#[doc(hidden)]
+pub const fn __embed_files_len(files: &[&[u8]]) -> usize {
+	embed_file_offset(files, files.len())
+}
+
+#[doc(hidden)]
+pub const fn __embed_files_data<const N: usize>(files: &[&[u8]]) -> [u8; N] {
+	let mut data = [0u8; N];
+	let mut i = 0;
+	while i < files.len() {
+		let offset = embed_file_offset(files, i);
+		let mut j = 0;
+		while j < files[i].len() {
+			data[offset + j] = files[i][j];
+			j += 1;
+		}
+		i += 1;
+	}
+	data
+}
+
+#[doc(hidden)]
+pub const fn __embed_files_entries<const N: usize>(names: &[&'static str], files: &[&[u8]]) -> [EmbeddedFile; N] {
+	let mut entries = [EmbeddedFile { name: "", offset: 0, len: 0 }; N];
+	let mut i = 0;
+	while i < N {
+		entries[i] = EmbeddedFile { name: names[i], offset: embed_file_offset(files, i), len: files[i].len() };
+		i += 1;
+	}
+	entries
+}
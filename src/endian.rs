@@ -0,0 +1,117 @@
+use core::{fmt, ptr};
+use super::*;
+
+/// Reverses the byte order of integers, floats and arrays thereof.
+pub trait SwapBytes: Copy {
+	/// Returns the value with its bytes in reverse order.
+	fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_swap_bytes {
+	($($ty:ty)*) => {
+		$(impl SwapBytes for $ty {
+			#[inline]
+			fn swap_bytes(self) -> $ty {
+				<$ty>::swap_bytes(self)
+			}
+		})*
+	};
+}
+impl_swap_bytes!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl SwapBytes for f32 {
+	#[inline]
+	fn swap_bytes(self) -> f32 {
+		f32::from_bits(self.to_bits().swap_bytes())
+	}
+}
+impl SwapBytes for f64 {
+	#[inline]
+	fn swap_bytes(self) -> f64 {
+		f64::from_bits(self.to_bits().swap_bytes())
+	}
+}
+impl<T: SwapBytes, const N: usize> SwapBytes for [T; N] {
+	#[inline]
+	fn swap_bytes(self) -> [T; N] {
+		self.map(T::swap_bytes)
+	}
+}
+
+macro_rules! endian_wrapper {
+	($name:ident, $endian:literal, $order:literal, $bytes:expr) => {
+		#[doc = concat!("Wrapper storing a value in ", $order, " byte order.")]
+		///
+		/// The wrapper has an alignment of 1 like [`Unalign`], the value is converted from and to the native byte order by
+		#[doc = concat!("[`get`](", stringify!($name), "::get) and [`set`](", stringify!($name), "::set).")]
+		///
+		/// ```
+		#[doc = concat!("use dataview::{DataView, ", stringify!($name), "};")]
+		///
+		#[doc = concat!("let view = DataView::from(&", stringify!($bytes), ");")]
+		#[doc = concat!("assert_eq!(view.get::<", stringify!($name), "<u16>>(1).get(), 0x1234);")]
+		/// ```
+		#[repr(C, packed)]
+		pub struct $name<T>(T);
+
+		unsafe impl<T: Pod> Pod for $name<T> {}
+
+		impl<T: SwapBytes> $name<T> {
+			#[doc = concat!("Wraps the value, converting it to ", $order, " byte order.")]
+			#[inline]
+			pub fn new(value: T) -> $name<T> {
+				$name(if cfg!(target_endian = $endian) { value } else { value.swap_bytes() })
+			}
+			/// Returns a copy of the value in native byte order.
+			#[inline]
+			pub fn get(&self) -> T {
+				let value = unsafe { ptr::read_unaligned(ptr::addr_of!(self.0)) };
+				if cfg!(target_endian = $endian) { value } else { value.swap_bytes() }
+			}
+			/// Replaces the value.
+			#[inline]
+			pub fn set(&mut self, value: T) {
+				*self = $name::new(value);
+			}
+		}
+
+		impl<T: Copy> Copy for $name<T> {}
+		impl<T: Copy> Clone for $name<T> {
+			#[inline]
+			fn clone(&self) -> $name<T> {
+				*self
+			}
+		}
+
+		impl<T: SwapBytes + Default> Default for $name<T> {
+			#[inline]
+			fn default() -> $name<T> {
+				$name::new(T::default())
+			}
+		}
+
+		impl<T: SwapBytes> From<T> for $name<T> {
+			#[inline]
+			fn from(value: T) -> $name<T> {
+				$name::new(value)
+			}
+		}
+
+		impl<T: SwapBytes + PartialEq> PartialEq for $name<T> {
+			#[inline]
+			fn eq(&self, other: &$name<T>) -> bool {
+				self.get() == other.get()
+			}
+		}
+		impl<T: SwapBytes + Eq> Eq for $name<T> {}
+
+		impl<T: SwapBytes + fmt::Debug> fmt::Debug for $name<T> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+			}
+		}
+	};
+}
+
+endian_wrapper!(Le, "little", "little endian", [0u8, 0x34, 0x12]);
+endian_wrapper!(Be, "big", "big endian", [0u8, 0x12, 0x34]);
@@ -1,50 +1,122 @@
+use core::{fmt, marker::PhantomData, ops};
+use super::*;
+use crate::data_view::invalid_offset;
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! __field_offsets {
-	(
-		$(#$meta:tt)*
-		$vis:vis struct $name:ident {
-			$(
-				$(#[$field_meta:meta])*
-				$field_vis:vis $field_name:ident: $field_ty:ty
-			),*
-			$(,)?
-		}
-	) => {
-		const _: () = {
-			#[derive(Copy, Clone, Debug)]
-			$vis struct FieldOffsets {
-				$($field_vis $field_name: usize,)*
-			}
-			impl $name where Self: $crate::Pod {
-				const FIELD_OFFSETS: FieldOffsets = $crate::__field_offsets_impl!(0usize; {} $($field_name: $field_ty,)*);
-			}
-		};
-	};
+/// Describes a field of a struct.
+///
+/// A table of these is generated by the `FieldOffsets` derive macro with the `#[pod(fields)]` attribute.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FieldInfo {
+	/// Name of the field.
+	pub name: &'static str,
+	/// Offset of the field in bytes.
+	pub offset: usize,
+	/// Size of the field in bytes.
+	pub size: usize,
+}
+
+impl FieldInfo {
+	/// Returns the `start..end` byte range of the field.
+	#[inline]
+	pub const fn span(&self) -> ops::Range<usize> {
+		self.offset..self.offset + self.size
+	}
+	/// Finds a field by name in a table of fields.
+	#[inline]
+	pub fn find<'a>(fields: &'a [FieldInfo], name: &str) -> Option<&'a FieldInfo> {
+		fields.iter().find(|field| field.name == name)
+	}
 }
 
+// Names the field offsets type generated by the FieldOffsets derive macro for nested fields
 #[doc(hidden)]
-#[macro_export]
-macro_rules! __field_offsets_impl {
-	(
-		$offset:expr;
-		{$($init_name:ident: $init_expr:expr,)*}
-		$field_name:ident: $field_ty:ty,
-		$($tail_name:ident: $tail_ty:ty,)*
-	) => {
-		$crate::__field_offsets_impl!(
-			$offset + ::core::mem::size_of::<$field_ty>();
-			{ $($init_name: $init_expr,)* $field_name: $offset, }
-			$($tail_name: $tail_ty,)*
-		)
-	};
-	(
-		$offset:expr;
-		{$($init_name:ident: $init_expr:expr,)*}
-	) => {
-		FieldOffsets {
-			$($init_name: $init_expr,)*
+pub trait __FieldOffsets {
+	type FieldOffsets;
+}
+
+/// Typed offset of a field.
+///
+/// Generated for every field by the `FieldOffsets` derive macro in the `TYPED_OFFSETS` associated constant.
+/// Reading a field through its typed offset does not require restating its type:
+///
+/// ```
+/// #[derive(dataview::Pod, dataview::FieldOffsets)]
+/// #[repr(C)]
+/// struct Record {
+/// 	id: u32,
+/// 	count: u16,
+/// 	flags: u16,
+/// }
+///
+/// let record = Record { id: 1, count: 2, flags: 3 };
+/// let view = dataview::DataView::from(&record);
+/// let count = view.field(Record::TYPED_OFFSETS.count);
+/// assert_eq!(count, 2u16);
+/// ```
+pub struct Field<T> {
+	offset: usize,
+	_marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Field<T> {
+	/// Creates a typed offset.
+	#[inline]
+	pub const fn new(offset: usize) -> Field<T> {
+		Field { offset, _marker: PhantomData }
+	}
+	/// Returns the offset in bytes.
+	#[inline]
+	pub const fn offset(self) -> usize {
+		self.offset
+	}
+	/// Returns the `start..end` byte range of the field.
+	#[inline]
+	pub const fn span(self) -> ops::Range<usize> {
+		self.offset..self.offset + mem::size_of::<T>()
+	}
+}
+
+impl<T> Copy for Field<T> {}
+impl<T> Clone for Field<T> {
+	#[inline]
+	fn clone(&self) -> Field<T> {
+		*self
+	}
+}
+impl<T> fmt::Debug for Field<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("Field").field(&self.offset).finish()
+	}
+}
+
+/// Accesses fields through their typed offsets.
+impl DataView {
+	/// Reads a field from the view.
+	#[inline]
+	pub fn try_field<T: AnyBitPattern>(&self, field: Field<T>) -> Option<T> {
+		self.try_read(field.offset)
+	}
+	/// Reads a field from the view.
+	#[track_caller]
+	#[inline]
+	pub fn field<T: AnyBitPattern>(&self, field: Field<T>) -> T {
+		match self.try_field(field) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Gets an aligned mutable reference to a field in the view.
+	#[inline]
+	pub fn try_field_mut<T: Pod>(&mut self, field: Field<T>) -> Option<&mut T> {
+		self.try_get_mut(field.offset)
+	}
+	/// Gets an aligned mutable reference to a field in the view.
+	#[track_caller]
+	#[inline]
+	pub fn field_mut<T: Pod>(&mut self, field: Field<T>) -> &mut T {
+		match self.try_field_mut(field) {
+			Some(value) => value,
+			None => invalid_offset(),
 		}
-	};
+	}
 }
@@ -0,0 +1,100 @@
+/// Defines a struct and invokes a callback macro for each of its fields.
+///
+/// The callback is invoked in item position with the struct name, the field name and type,
+/// followed by the `(name, offset, size)` of the field as constant expressions:
+///
+/// ```
+/// macro_rules! check_field {
+/// 	($ty:ident, $field:ident: $field_ty:ty, $name:expr, $offset:expr, $size:expr) => {
+/// 		const _: () = assert!($offset + $size <= core::mem::size_of::<$ty>());
+/// 	};
+/// }
+///
+/// dataview::fields_of! {
+/// 	check_field;
+/// 	#[derive(dataview::Pod)]
+/// 	#[repr(C)]
+/// 	pub struct Header {
+/// 		pub magic: u32,
+/// 		pub len: u32,
+/// 	}
+/// }
+///
+/// let header = Header { magic: 1, len: 2 };
+/// assert_eq!(header.len, 2);
+/// ```
+///
+/// The syntax is `$callback; $struct_definition`.
+/// The callback may be a path to a macro, eg. `crate::my_macro`.
+///
+/// No support for generics, tuple structs or unions.
+#[macro_export]
+macro_rules! fields_of {
+	($($callback:ident)::+; $($item:tt)*) => {
+		$crate::fields_of!(@struct [$($callback)::+] $($item)*);
+	};
+	(
+		@struct $callback:tt
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident {
+			$(
+				$(#[$field_meta:meta])*
+				$field_vis:vis $field_name:ident: $field_ty:ty
+			),*
+			$(,)?
+		}
+	) => {
+		$(#[$meta])*
+		$vis struct $name {
+			$(
+				$(#[$field_meta])*
+				$field_vis $field_name: $field_ty,
+			)*
+		}
+		$(
+			$crate::fields_of!(@field $callback $name, $field_name: $field_ty);
+		)*
+	};
+	(@field [$($callback:tt)*] $name:ident, $field_name:ident: $field_ty:ty) => {
+		$($callback)*! {
+			$name,
+			$field_name: $field_ty,
+			::core::stringify!($field_name),
+			$crate::offset_of!($name.$field_name),
+			::core::mem::size_of::<$field_ty>()
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::FieldInfo;
+
+	macro_rules! field_info {
+		($ty:ident, $field:ident: $field_ty:ty, $name:expr, $offset:expr, $size:expr) => {
+			impl $ty {
+				#[allow(non_upper_case_globals)]
+				const $field: FieldInfo = FieldInfo { name: $name, offset: $offset, size: $size };
+			}
+		};
+	}
+
+	crate::fields_of! {
+		field_info;
+		#[repr(C)]
+		struct Record {
+			id: u16,
+			/// Documented field
+			value: u64,
+			flags: [u8; 3],
+		}
+	}
+
+	#[test]
+	fn callback_per_field() {
+		let _ = Record { id: 0, value: 0, flags: [0; 3] };
+		assert_eq!(Record::id, FieldInfo { name: "id", offset: 0, size: 2 });
+		assert_eq!(Record::value, FieldInfo { name: "value", offset: 8, size: 8 });
+		assert_eq!(Record::flags, FieldInfo { name: "flags", offset: 16, size: 3 });
+	}
+}
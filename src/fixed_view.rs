@@ -0,0 +1,125 @@
+use core::{fmt, ops, ptr};
+use core::marker::PhantomData;
+use super::*;
+
+/// Fixed size byte buffer with compiletime checked accessors.
+///
+/// Derefs to [`DataView`] for the full runtime checked API.
+///
+/// The `*_at` methods take their offset as a const generic argument and check at compiletime that the access is in bounds:
+///
+/// ```
+/// let mut packet = dataview::FixedView::<8>::zeroed();
+/// packet.write_at::<u16, 6>(&0x1234);
+/// assert_eq!(packet.read_at::<u16, 6>(), 0x1234);
+/// ```
+///
+/// Out of bounds accesses fail to compile:
+///
+/// ```compile_fail
+/// let packet = dataview::FixedView::<8>::zeroed();
+/// let _ = packet.read_at::<u32, 6>();
+/// ```
+///
+/// Note that these errors are raised during monomorphization, they are not reported by `cargo check`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct FixedView<const N: usize> {
+	bytes: [u8; N],
+}
+
+unsafe impl<const N: usize> Pod for FixedView<N> {}
+
+struct InBounds<T, const OFFSET: usize, const N: usize>(PhantomData<T>);
+impl<T, const OFFSET: usize, const N: usize> InBounds<T, OFFSET, N> {
+	const OK: () = assert!(OFFSET <= N && mem::size_of::<T>() <= N - OFFSET, "offset out of bounds");
+}
+
+impl<const N: usize> FixedView<N> {
+	/// Wraps a byte array.
+	#[inline]
+	pub const fn new(bytes: [u8; N]) -> FixedView<N> {
+		FixedView { bytes }
+	}
+	/// Returns a zero-initialized instance.
+	#[inline]
+	pub const fn zeroed() -> FixedView<N> {
+		FixedView { bytes: [0; N] }
+	}
+	/// Returns a fixed view into the byte array.
+	#[inline]
+	pub fn from_ref(bytes: &[u8; N]) -> &FixedView<N> {
+		unsafe { &*(bytes as *const [u8; N] as *const FixedView<N>) }
+	}
+	/// Returns a mutable fixed view into the byte array.
+	#[inline]
+	pub fn from_mut(bytes: &mut [u8; N]) -> &mut FixedView<N> {
+		unsafe { &mut *(bytes as *mut [u8; N] as *mut FixedView<N>) }
+	}
+	/// Returns the underlying byte array.
+	#[inline]
+	pub const fn into_inner(self) -> [u8; N] {
+		self.bytes
+	}
+	/// Reads a (potentially unaligned) value at a compiletime checked offset.
+	#[allow(clippy::let_unit_value)]
+	#[inline]
+	pub fn read_at<T: Pod, const OFFSET: usize>(&self) -> T {
+		let () = InBounds::<T, OFFSET, N>::OK;
+		unsafe { ptr::read_unaligned(self.bytes.as_ptr().add(OFFSET) as *const T) }
+	}
+	/// Writes a value at a compiletime checked offset.
+	#[allow(clippy::let_unit_value)]
+	#[inline]
+	pub fn write_at<T: Pod, const OFFSET: usize>(&mut self, value: &T) {
+		let () = InBounds::<T, OFFSET, N>::OK;
+		unsafe { ptr::write_unaligned(self.bytes.as_mut_ptr().add(OFFSET) as *mut T, ptr::read(value)) }
+	}
+}
+
+impl<const N: usize> Default for FixedView<N> {
+	#[inline]
+	fn default() -> FixedView<N> {
+		FixedView::zeroed()
+	}
+}
+
+impl<const N: usize> From<[u8; N]> for FixedView<N> {
+	#[inline]
+	fn from(bytes: [u8; N]) -> FixedView<N> {
+		FixedView { bytes }
+	}
+}
+
+impl<const N: usize> ops::Deref for FixedView<N> {
+	type Target = DataView;
+	#[inline]
+	fn deref(&self) -> &DataView {
+		DataView::from(&self.bytes)
+	}
+}
+impl<const N: usize> ops::DerefMut for FixedView<N> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut DataView {
+		DataView::from_mut(&mut self.bytes)
+	}
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedView<N> {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		&self.bytes
+	}
+}
+impl<const N: usize> AsMut<[u8]> for FixedView<N> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [u8] {
+		&mut self.bytes
+	}
+}
+
+impl<const N: usize> fmt::Debug for FixedView<N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("FixedView").field(&&self.bytes[..]).finish()
+	}
+}
@@ -0,0 +1,64 @@
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// Extension methods for `heapless::Vec<u8, N>` byte buffers.
+///
+/// Gives allocator free code a growable buffer with a fixed capacity to append Pod values to.
+///
+/// ```
+/// use dataview::HeaplessVecExt;
+///
+/// let mut buf = heapless::Vec::<u8, 8>::new();
+/// let offset = buf.push_pod(&0x1234_u16);
+/// buf.push_pod(&[1u8, 2, 3]);
+/// assert_eq!(offset, 0);
+/// assert_eq!(buf.as_data_view().read::<u16>(0), 0x1234);
+///
+/// // Does not fit in the remaining capacity
+/// assert_eq!(buf.try_push_pod(&0u32), None);
+/// assert_eq!(buf.len(), 5);
+/// ```
+///
+/// The bytes are only aligned to 1 byte, prefer the unaligned `read` and `write` methods over `get` and `slice`.
+pub trait HeaplessVecExt {
+	/// Returns a data view over the bytes.
+	fn as_data_view(&self) -> &DataView;
+	/// Returns a mutable data view over the bytes.
+	fn as_data_view_mut(&mut self) -> &mut DataView;
+	/// Appends the bytes of a value.
+	///
+	/// Returns the offset of the value or `None` if the value does not fit.
+	fn try_push_pod<T: ?Sized + AsBytes>(&mut self, value: &T) -> Option<usize>;
+	/// Appends the bytes of a value.
+	///
+	/// Returns the offset of the value.
+	///
+	/// # Panics
+	///
+	/// Panics with the message `"invalid offset"` if the value does not fit.
+	#[track_caller]
+	#[inline]
+	fn push_pod<T: ?Sized + AsBytes>(&mut self, value: &T) -> usize {
+		match self.try_push_pod(value) {
+			Some(offset) => offset,
+			None => invalid_offset(),
+		}
+	}
+}
+
+impl<const N: usize> HeaplessVecExt for heapless::Vec<u8, N> {
+	#[inline]
+	fn as_data_view(&self) -> &DataView {
+		DataView::from(&self[..])
+	}
+	#[inline]
+	fn as_data_view_mut(&mut self) -> &mut DataView {
+		DataView::from_mut(&mut self[..])
+	}
+	#[inline]
+	fn try_push_pod<T: ?Sized + AsBytes>(&mut self, value: &T) -> Option<usize> {
+		let offset = self.len();
+		self.extend_from_slice(bytes(value)).ok()?;
+		Some(offset)
+	}
+}
@@ -0,0 +1,115 @@
+use core::{ops, str};
+use super::*;
+use crate::data_view::invalid_offset;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+#[inline]
+fn hex_digit(c: u8) -> Option<u8> {
+	match c {
+		b'0'..=b'9' => Some(c - b'0'),
+		b'a'..=b'f' => Some(c - b'a' + 10),
+		b'A'..=b'F' => Some(c - b'A' + 10),
+		_ => None,
+	}
+}
+
+// Iterates over the bytes encoded in the hex string, whitespace is allowed between bytes
+fn hex_bytes(hex: &str) -> impl Iterator<Item = Option<u8>> + '_ {
+	let mut chars = hex.bytes().filter(|c| !c.is_ascii_whitespace());
+	core::iter::from_fn(move || {
+		let hi = chars.next()?;
+		let lo = chars.next();
+		Some(match (hex_digit(hi), lo.and_then(hex_digit)) {
+			(Some(hi), Some(lo)) => Some(hi << 4 | lo),
+			_ => None,
+		})
+	})
+}
+
+//----------------------------------------------------------------
+
+/// Converts between hex text and the bytes of the view.
+impl DataView {
+	/// Decodes the hex string and writes the bytes at the offset.
+	///
+	/// Both upper and lower case digits are accepted and ASCII whitespace is ignored.
+	/// Returns the number of bytes written or `None` if the string is not valid hex or the bytes are out of bounds, in which case nothing is written.
+	///
+	/// ```
+	/// let mut bytes = [0u8; 6];
+	/// let view = dataview::DataView::from_mut(&mut bytes);
+	/// assert_eq!(view.try_write_hex(1, "DEAD be ef"), Some(4));
+	/// assert_eq!(view.try_write_hex(4, "c0ffee"), None);
+	/// assert_eq!(view.try_write_hex(0, "0g"), None);
+	/// assert_eq!(bytes, [0, 0xde, 0xad, 0xbe, 0xef, 0]);
+	/// ```
+	#[inline]
+	pub fn try_write_hex(&mut self, offset: usize, hex: &str) -> Option<usize> {
+		let mut len = 0usize;
+		for byte in hex_bytes(hex) {
+			byte?;
+			len += 1;
+		}
+		let dest = self.as_mut().get_mut(offset..offset.checked_add(len)?)?;
+		for (dest, byte) in dest.iter_mut().zip(hex_bytes(hex)) {
+			*dest = byte?;
+		}
+		Some(len)
+	}
+	/// Decodes the hex string and writes the bytes at the offset.
+	///
+	/// Returns the number of bytes written.
+	#[track_caller]
+	#[inline]
+	pub fn write_hex(&mut self, offset: usize, hex: &str) -> usize {
+		match self.try_write_hex(offset, hex) {
+			Some(len) => len,
+			None => invalid_offset(),
+		}
+	}
+	/// Encodes the bytes in the range as lower case hex into the destination buffer.
+	///
+	/// Returns the hex string or `None` if the range is out of bounds or the destination is too small.
+	///
+	/// ```
+	/// let view = dataview::DataView::from(&[0x01_u8, 0xab, 0xff]);
+	/// let mut buf = [0u8; 16];
+	/// assert_eq!(view.try_encode_hex(1.., &mut buf), Some("abff"));
+	/// assert_eq!(view.try_encode_hex(.., &mut buf[..5]), None);
+	/// ```
+	#[inline]
+	pub fn try_encode_hex<'a, R: ops::RangeBounds<usize>>(&self, range: R, dest: &'a mut [u8]) -> Option<&'a str> {
+		let src = self.index(range)?.as_ref();
+		let dest = dest.get_mut(..src.len().checked_mul(2)?)?;
+		for (pair, &byte) in dest.chunks_exact_mut(2).zip(src) {
+			pair[0] = HEX_DIGITS[(byte >> 4) as usize];
+			pair[1] = HEX_DIGITS[(byte & 0xf) as usize];
+		}
+		// Only ASCII hex digits were written
+		Some(unsafe { str::from_utf8_unchecked(dest) })
+	}
+	/// Encodes the bytes in the range as lower case hex into the destination buffer.
+	#[track_caller]
+	#[inline]
+	pub fn encode_hex<'a, R: ops::RangeBounds<usize>>(&self, range: R, dest: &'a mut [u8]) -> &'a str {
+		match self.try_encode_hex(range, dest) {
+			Some(hex) => hex,
+			None => invalid_offset(),
+		}
+	}
+	/// Encodes the bytes of the view as lower case hex.
+	///
+	/// ```
+	/// let bytes = 0x1234_u16.to_be_bytes();
+	/// assert_eq!(dataview::DataView::from(&bytes).to_hex_string(), "1234");
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[inline]
+	pub fn to_hex_string(&self) -> alloc::string::String {
+		let mut buf = alloc::vec![0u8; self.len() * 2];
+		self.encode_hex(.., &mut buf);
+		// Only ASCII hex digits were written
+		unsafe { alloc::string::String::from_utf8_unchecked(buf) }
+	}
+}
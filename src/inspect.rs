@@ -0,0 +1,81 @@
+use core::{fmt, ops};
+use super::*;
+
+/// Byte level view of a value which is not pod.
+///
+/// Types without padding whose fields do not accept every bit pattern, eg. containing `bool` or `char`, implement [`AsBytes`] but not [`Pod`].
+/// Their bytes can still be read safely, this wrapper derefs to a read-only [`DataView`] over the value and formats it as a hexdump.
+///
+/// ```
+/// use dataview::{AsBytes, Inspect};
+///
+/// #[repr(C)]
+/// struct Flags {
+/// 	enabled: bool,
+/// 	visible: bool,
+/// 	id: u16,
+/// }
+/// unsafe impl AsBytes for Flags {}
+///
+/// let flags = Flags { enabled: true, visible: false, id: 0x0302 };
+/// let inspect = Inspect::new(&flags);
+/// assert_eq!(inspect.read::<u8>(0), 1);
+/// assert_eq!(inspect.read::<u16>(2), 0x0302);
+/// assert_eq!(format!("{:?}", Inspect::new(&[1u8, 0xff])), "00000000: 01 ff");
+/// ```
+#[repr(transparent)]
+pub struct Inspect<T: ?Sized + AsBytes>(T);
+
+impl<T: ?Sized + AsBytes> Inspect<T> {
+	/// Wraps a reference to the value.
+	#[inline]
+	pub fn new(value: &T) -> &Inspect<T> {
+		unsafe { &*(value as *const T as *const Inspect<T>) }
+	}
+	/// Returns the inspected value.
+	#[inline]
+	pub fn get(&self) -> &T {
+		&self.0
+	}
+	/// Returns the bytes of the value.
+	#[inline]
+	pub fn as_bytes(&self) -> &[u8] {
+		bytes(&self.0)
+	}
+	/// Returns the bytes of the value as a data view.
+	#[inline]
+	pub fn as_data_view(&self) -> &DataView {
+		DataView::from(self.as_bytes())
+	}
+}
+
+impl<T: ?Sized + AsBytes> ops::Deref for Inspect<T> {
+	type Target = DataView;
+	#[inline]
+	fn deref(&self) -> &DataView {
+		self.as_data_view()
+	}
+}
+
+impl<T: ?Sized + AsBytes> AsRef<[u8]> for Inspect<T> {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+// Lines of 16 bytes prefixed with their offset
+impl<T: ?Sized + AsBytes> fmt::Debug for Inspect<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (index, line) in self.as_bytes().chunks(16).enumerate() {
+			if index != 0 {
+				f.write_str("\n")?;
+			}
+			write!(f, "{:08x}:", index * 16)?;
+			for byte in line {
+				write!(f, " {:02x}", byte)?;
+			}
+		}
+		Ok(())
+	}
+}
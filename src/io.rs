@@ -0,0 +1,113 @@
+use core::convert::TryFrom;
+use std::{fs, io};
+use std::path::Path;
+use alloc::boxed::Box;
+use super::*;
+
+/// Extension methods reading Pod values from a reader.
+///
+/// ```
+/// use dataview::ReadPodExt;
+///
+/// #[derive(dataview::Pod)]
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: [u8; 4],
+/// 	len: u32,
+/// }
+///
+/// let mut file: &[u8] = b"DATA\x05\x00\x00\x00hello";
+/// let header: Header = file.read_pod()?;
+/// assert_eq!(&header.magic, b"DATA");
+///
+/// let mut body = [0u8; 5];
+/// file.read_pod_into(&mut body)?;
+/// assert_eq!(&body, b"hello");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub trait ReadPodExt: io::Read {
+	/// Reads exactly `size_of::<T>()` bytes into a new value.
+	#[inline]
+	fn read_pod<T: Pod>(&mut self) -> io::Result<T> {
+		let mut value = zeroed::<T>();
+		self.read_exact(bytes_mut(&mut value))?;
+		Ok(value)
+	}
+	/// Reads exactly `size_of_val(dest)` bytes into the destination.
+	#[inline]
+	fn read_pod_into<T: ?Sized + Pod>(&mut self, dest: &mut T) -> io::Result<()> {
+		self.read_exact(bytes_mut(dest))
+	}
+}
+
+impl<R: ?Sized + io::Read> ReadPodExt for R {}
+
+/// Extension methods writing Pod values to a writer.
+///
+/// ```
+/// use dataview::WritePodExt;
+///
+/// let mut file = Vec::new();
+/// file.write_pod(b"DATA")?;
+/// file.write_pod(&5u32.to_le_bytes())?;
+/// file.write_pod("hello")?;
+/// assert_eq!(file, b"DATA\x05\x00\x00\x00hello");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub trait WritePodExt: io::Write {
+	/// Writes all the bytes of the value.
+	#[inline]
+	fn write_pod<T: ?Sized + AsBytes>(&mut self, value: &T) -> io::Result<()> {
+		self.write_all(bytes(value))
+	}
+}
+
+impl<W: ?Sized + io::Write> WritePodExt for W {}
+
+//----------------------------------------------------------------
+
+/// Loads files into owned buffers.
+impl DataViewBuf {
+	/// Reads the whole file into a new buffer.
+	///
+	/// ```no_run
+	/// let buf = dataview::DataViewBuf::read_file("data.bin")?;
+	/// let magic: [u8; 4] = buf.read(0);
+	/// # Ok::<(), std::io::Error>(())
+	/// ```
+	#[inline]
+	pub fn read_file(path: impl AsRef<Path>) -> io::Result<DataViewBuf> {
+		fs::read(path).map(DataViewBuf::from)
+	}
+	/// Reads the whole file into a new slice aligned for `T`.
+	///
+	/// The slice is sized from the file's metadata and rounded up to whole elements, the trailing bytes are zeroed.
+	/// Unlike [`read_file`](DataViewBuf::read_file) the aligned `get` and `slice` methods can be used on the bytes.
+	///
+	/// ```no_run
+	/// use dataview::{DataView, DataViewBuf};
+	///
+	/// let table = DataViewBuf::read_file_aligned::<u64>("table.bin")?;
+	/// let view = DataView::from(&*table);
+	/// let entries: &[u32] = view.slice(8, 4);
+	/// # Ok::<(), std::io::Error>(())
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if `T` is a zero sized type.
+	pub fn read_file_aligned<T: Pod>(path: impl AsRef<Path>) -> io::Result<Box<[T]>> {
+		let size = mem::size_of::<T>();
+		if size == 0 {
+			panic!("cannot read files into zero sized types");
+		}
+		let mut file = fs::File::open(path)?;
+		let len = match usize::try_from(file.metadata()?.len()) {
+			Ok(len) => len,
+			Err(_) => return Err(io::Error::new(io::ErrorKind::OutOfMemory, "file too large")),
+		};
+		let mut slice = boxed_slice_zeroed::<T>(len.div_ceil(size));
+		io::Read::read_exact(&mut file, &mut bytes_mut(&mut *slice)[..len])?;
+		Ok(slice)
+	}
+}
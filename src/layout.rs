@@ -0,0 +1,32 @@
+use super::*;
+
+/// Describes the memory layout of a type.
+///
+/// Implemented with the [derive macro](derive@Layout), which does not require the type to implement `Pod`.
+///
+/// ```
+/// #[derive(dataview::Layout)]
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: u32,
+/// 	flags: u8,
+/// }
+///
+/// use dataview::Layout;
+/// assert_eq!(Header::SIZE, 8);
+/// assert_eq!(Header::fields()[1].name, "flags");
+/// assert_eq!(Header::fields()[1].span(), 4..5);
+/// ```
+pub trait Layout: Sized {
+	/// The size of the type in bytes.
+	const SIZE: usize = mem::size_of::<Self>();
+	/// The alignment of the type in bytes.
+	const ALIGN: usize = mem::align_of::<Self>();
+	/// Table of the name, offset and size of every field in declaration order.
+	const FIELDS: &'static [FieldInfo];
+	/// Returns the table of fields.
+	#[inline]
+	fn fields() -> &'static [FieldInfo] {
+		Self::FIELDS
+	}
+}
@@ -0,0 +1,186 @@
+/// Defines a packed [`Pod`](crate::Pod) struct with an on-the-wire layout and methods to parse and emit it.
+///
+/// Fields are laid out back to back without padding in declaration order, the struct is `#[repr(C, packed)]`.
+/// Each field may specify its byte order with `as le`, `as be` or `as ne` (the default),
+/// fields with `le` or `be` are stored in the [`Le`](crate::Le) and [`Be`](crate::Be) wrappers which convert the byte order on access.
+/// Explicit padding is a named byte array field declared as `name: pad(N)`.
+///
+/// ```
+/// use dataview::{Be, Le};
+///
+/// dataview::layout! {
+/// 	#[derive(Copy, Clone, Debug, PartialEq)]
+/// 	pub struct Header {
+/// 		pub magic: [u8; 4],
+/// 		pub version: u16 as be,
+/// 		_reserved: pad(2),
+/// 		pub lengths: [u32; 2] as le,
+/// 	}
+/// }
+///
+/// let bytes = *b"DATA\x00\x01\xff\xff\x02\x00\x00\x00\x03\x00\x00\x00";
+/// let mut header = Header::parse(dataview::DataView::from(&bytes));
+/// assert_eq!(header.magic, *b"DATA");
+/// assert_eq!(header.version.get(), 1);
+/// assert_eq!(header.lengths.get(), [2, 3]);
+/// assert_eq!(Header::SIZE, 16);
+///
+/// header.version.set(2);
+/// assert_eq!(header.version, Be::new(2));
+/// header._reserved = [0; 2];
+/// let mut out = [0xaa; 16];
+/// header.emit(dataview::DataView::from_mut(&mut out));
+/// assert_eq!(out[4..8], [0, 2, 0, 0]);
+/// assert_eq!(out[8..], bytes[8..]);
+///
+/// // The struct is Pod and can be viewed in place
+/// let view = dataview::DataView::from(&bytes);
+/// assert_eq!(view.get::<Header>(0).lengths, Le::new([2, 3]));
+/// ```
+///
+/// The field types must implement [`Pod`](crate::Pod), fields with a byte order must implement [`SwapBytes`](crate::SwapBytes).
+/// Derives on the packed struct require the fields to be `Copy`, include `#[derive(Copy, Clone)]` when deriving other traits.
+///
+/// Padding must be named, the bytes are kept as they were parsed:
+///
+/// ```compile_fail
+/// dataview::layout! {
+/// 	struct Header {
+/// 		magic: u32,
+/// 		_: pad(4),
+/// 	}
+/// }
+/// ```
+///
+/// The generated methods are:
+///
+/// * `const SIZE: usize`: The size of the layout in bytes.
+/// * `try_parse(&DataView) -> Option<Self>` and `parse(&DataView) -> Self`: Reads the struct from the start of the view.
+/// * `try_emit(&self, &mut DataView) -> Option<()>` and `emit(&self, &mut DataView)`: Writes the struct to the start of the view.
+///
+/// The panicking methods panic with the message `"invalid offset"` if the view is too small, see [`DataView`](crate::DataView).
+#[macro_export]
+macro_rules! layout {
+	(
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident {
+			$($body:tt)*
+		}
+	) => {
+		$crate::layout!(@munch [$(#[$meta])* $vis struct $name] [] $($body)*);
+	};
+
+	// Unnamed padding
+	(@munch $head:tt $fields:tt _: pad($n:expr) $(, $($tail:tt)*)?) => {
+		::core::compile_error!("padding must be named, eg. `_reserved: pad(N)`");
+	};
+	// Padding
+	(@munch $head:tt [$($fields:tt)*]
+		$(#[$field_meta:meta])*
+		$field_vis:vis $field:ident: pad($n:expr)
+		$(, $($tail:tt)*)?
+	) => {
+		$crate::layout!(@munch $head
+			[$($fields)* $(#[$field_meta])* $field_vis $field: [u8; $n],]
+			$($($tail)*)?
+		);
+	};
+	// Fields
+	(@munch $head:tt [$($fields:tt)*]
+		$(#[$field_meta:meta])*
+		$field_vis:vis $field:ident: $ty:ty $(as $endian:ident)?
+		$(, $($tail:tt)*)?
+	) => {
+		$crate::layout!(@munch $head
+			[$($fields)* $(#[$field_meta])* $field_vis $field: $crate::layout!(@order $ty, $($endian)?),]
+			$($($tail)*)?
+		);
+	};
+	// Emit the struct
+	(@munch [$(#[$meta:meta])* $vis:vis struct $name:ident] [$($(#[$field_meta:meta])* $field_vis:vis $field:ident: $ty:ty,)*]) => {
+		$(#[$meta])*
+		#[repr(C, packed)]
+		$vis struct $name {
+			$($(#[$field_meta])* $field_vis $field: $ty,)*
+		}
+
+		// Packed structs have no padding
+		unsafe impl $crate::Pod for $name where $($ty: $crate::Pod,)* {}
+
+		#[allow(dead_code)]
+		impl $name {
+			/// The size of the layout in bytes.
+			pub const SIZE: usize = ::core::mem::size_of::<$name>();
+
+			/// Reads the struct from the start of the view.
+			#[inline]
+			pub fn try_parse(view: &$crate::DataView) -> ::core::option::Option<$name> {
+				view.try_read(0)
+			}
+			/// Reads the struct from the start of the view.
+			#[track_caller]
+			#[inline]
+			pub fn parse(view: &$crate::DataView) -> $name {
+				view.read(0)
+			}
+			/// Writes the struct to the start of the view.
+			#[inline]
+			pub fn try_emit(&self, view: &mut $crate::DataView) -> ::core::option::Option<()> {
+				view.try_write(0, self)
+			}
+			/// Writes the struct to the start of the view.
+			#[track_caller]
+			#[inline]
+			pub fn emit(&self, view: &mut $crate::DataView) {
+				view.write(0, self)
+			}
+		}
+	};
+
+	// The field type storing the given byte order
+	(@order $ty:ty,) => { $ty };
+	(@order $ty:ty, ne) => { $ty };
+	(@order $ty:ty, le) => { $crate::Le<$ty> };
+	(@order $ty:ty, be) => { $crate::Be<$ty> };
+	(@order $ty:ty, $endian:ident) => {
+		::core::compile_error!(::core::concat!("unknown byte order `", ::core::stringify!($endian), "`, expected `le`, `be` or `ne`"))
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Be, DataView, Le};
+
+	crate::layout! {
+		#[derive(Copy, Clone, Debug, PartialEq)]
+		struct Record {
+			tag: u8,
+			value: u32 as le,
+			big: i16 as be,
+			_pad: pad(1),
+			floats: [f32; 2] as be,
+			native: u16 as ne,
+		}
+	}
+
+	#[test]
+	fn parse_emit() {
+		assert_eq!(Record::SIZE, 18);
+		assert_eq!(core::mem::align_of::<Record>(), 1);
+		let record = Record { tag: 7, value: Le::new(0x01020304), big: Be::new(-2), _pad: [0], floats: Be::new([1.0, -1.0]), native: 5 };
+		let mut bytes = [0xff; 20];
+		record.emit(DataView::from_mut(&mut bytes));
+		assert_eq!(bytes[..8], [7, 4, 3, 2, 1, 0xff, 0xfe, 0]);
+		assert_eq!(bytes[8..16], [0x3f, 0x80, 0, 0, 0xbf, 0x80, 0, 0]);
+		assert_eq!(bytes[16..18], 5u16.to_ne_bytes());
+		assert_eq!(bytes[18..], [0xff, 0xff]);
+		assert_eq!(Record::parse(DataView::from(&bytes)), record);
+		assert_eq!(Record::try_parse(DataView::from(&bytes[..17])), None);
+		assert_eq!(record.try_emit(DataView::from_mut(&mut bytes[3..])), None);
+
+		let view = DataView::from_mut(&mut bytes);
+		view.get_mut::<Record>(0).big.set(0x1234);
+		assert_eq!(view.as_ref()[5..7], [0x12, 0x34]);
+		assert_eq!(view.read::<Record>(0).value.get(), 0x01020304);
+	}
+}
@@ -26,10 +26,17 @@ assert_eq!(dataview::bytes(&inst), &[0, 0, 255, 0]);
 */
 
 #![no_std]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![allow(clippy::tabs_in_doc_comments)]
 
-use core::{mem, slice};
+use core::{cmp, hash, mem, num, ptr, slice};
 use core::marker::PhantomData;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 mod data_view;
 pub use self::data_view::DataView;
 
@@ -41,9 +48,152 @@ pub use ::derive_pod::Pod;
 #[doc(hidden)]
 pub use ::derive_pod::FieldOffsets;
 
-mod derive_pod;
+#[cfg(feature = "derive_pod")]
+#[doc(inline)]
+pub use ::derive_pod::TryPod;
+
+#[cfg(feature = "derive_pod")]
+#[doc(inline)]
+pub use ::derive_pod::WriteFields;
+
+#[cfg(feature = "derive_pod")]
+#[doc(inline)]
+pub use ::derive_pod::PodView;
+
+#[cfg(feature = "derive_pod")]
+#[doc(inline)]
+pub use ::derive_pod::Layout;
+
+pub mod cast;
+
+mod layout;
+pub use self::layout::Layout;
+
+mod aligned;
+pub use self::aligned::{Aligned, Alignment, A1, A2, A4, A8, A16, A32, A64, A128, A256, A512, A1024, A2048, A4096};
+
+mod unalign;
+pub use self::unalign::Unalign;
+
+mod inspect;
+pub use self::inspect::Inspect;
+
+mod endian;
+pub use self::endian::{Be, Le, SwapBytes};
+
+mod write_fields;
+pub use self::write_fields::WriteFields;
+
+mod try_pod;
+pub use self::try_pod::TryPod;
+
+mod policy;
+pub use self::policy::{BoundsPolicy, Checked, PolicyView, TrustedUnchecked, ZeroFill};
+
+mod valid_offset;
+pub use self::valid_offset::ValidOffset;
+
+mod fixed_view;
+pub use self::fixed_view::FixedView;
+
+mod zip_records;
+pub use self::zip_records::ZipRecords;
+
+mod strided_view;
+pub use self::strided_view::{StridedIter, StridedView};
+
+mod columns;
+pub use self::columns::{Column, ColumnBytes};
+
+mod memory_view;
+pub use self::memory_view::{MemoryView, MemoryViewMut};
+
+pub mod checksum;
+
+mod hex;
+
+mod atomic;
+pub use self::atomic::AtomicPod;
+
+mod serializer;
+pub use self::serializer::Serializer;
+
+mod arena;
+pub use self::arena::Arena;
+
 mod field_offsets;
+pub use self::field_offsets::{Field, FieldInfo};
+#[doc(hidden)]
+pub use self::field_offsets::__FieldOffsets;
 mod offset_of;
+#[doc(hidden)]
+pub use self::offset_of::{__array_offset, __field_size};
+mod fields_of;
+mod bitfield;
+mod layout_macro;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+pub use self::boxed::{boxed_slice_zeroed, boxed_zeroed};
+#[cfg(feature = "alloc")]
+mod buf;
+#[cfg(feature = "alloc")]
+pub use self::buf::{collect_vec, DataViewBuf};
+#[cfg(feature = "alloc")]
+mod cow;
+#[cfg(feature = "alloc")]
+pub use self::cow::CowView;
+
+#[cfg(feature = "serde")]
+pub mod serde_pod;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_compat;
+#[cfg(feature = "bytemuck")]
+pub use self::bytemuck_compat::Bytemuck;
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy_compat;
+#[cfg(feature = "zerocopy")]
+pub use self::zerocopy_compat::Zerocopy;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_pod;
+#[cfg(feature = "arbitrary")]
+pub use self::arbitrary_pod::ArbitraryPod;
+
+#[cfg(feature = "heapless")]
+mod heapless_vec;
+#[cfg(feature = "heapless")]
+pub use self::heapless_vec::HeaplessVecExt;
+
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rand")]
+pub use self::random::{fill_random, StandardPod};
+
+#[cfg(any(feature = "mint", feature = "glam"))]
+mod math;
+
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "std")]
+pub use self::io::{ReadPodExt, WritePodExt};
+
+#[cfg(feature = "mmap")]
+mod mapped_view;
+#[cfg(feature = "mmap")]
+pub use self::mapped_view::MappedView;
+
+mod embed;
+pub use self::embed::{EmbeddedDir, EmbeddedFile};
+#[doc(hidden)]
+pub use self::embed::{__EmbedScalar, __embed_bytes, __embed_files_data, __embed_files_entries, __embed_files_len, __embed_len, __embed_len_pad, __embed_len_scalar, __embed_len_truncate, __embed_swap, __embed_value_len};
+
+#[doc(hidden)]
+pub mod const_report;
+mod assert_layout;
 
 /// Types whose values can be safely transmuted between byte arrays of the same size.
 ///
@@ -53,10 +203,14 @@ mod offset_of;
 ///
 /// This is true for these primitive types: `i8`, `i16`, `i32`, `i64`, `i128`, `u8`, `u16`, `u32`, `u64`, `u128`, `f32`, `f64`.
 /// The raw pointer types are not pod under strict provenance rules but can be through the 'int2ptr' feature.
+/// This feature also covers `Option` of `unsafe extern "C"` function pointers with up to 6 arguments.
 /// Primitives such as `str` and `bool` are not pod because not every valid byte pattern is a valid instance of these types.
 /// References or types with lifetimes are _never_ pod.
 ///
+/// `Option` of the `NonZero` integer types are pod, the zero bit pattern represents `None`.
+///
 /// Arrays and slices of pod types are also pod themselves.
+/// Tuples are not pod, the layout of tuples is unspecified and may contain padding.
 ///
 /// Note that it is legal for pod types to be a [ZST](https://doc.rust-lang.org/nomicon/exotic-sizes.html#zero-sized-types-zsts).
 ///
@@ -66,20 +220,105 @@ mod offset_of;
 ///   or [`#[repr(transparent)]`](https://doc.rust-lang.org/nomicon/other-reprs.html#reprtransparent).
 /// * Must have every field's type implement `Pod` itself.
 /// * Must not have any padding between its fields, define dummy fields to cover the padding.
+/// * Unions must not have any fields smaller than the union itself.
 ///
 /// # Derive macro
 ///
 /// To help with safely implementing this trait for user defined types, a [derive macro](derive@Pod) is provided to implement the `Pod` trait if the requirements are satisfied.
+///
+/// # Capabilities
+///
+/// `Pod` is the combination of the finer grained [`Zeroable`], [`AnyBitPattern`] and [`AsBytes`] traits, which are implemented for every pod type.
+/// Types which do not meet all the requirements of `Pod` may still implement some of these traits directly.
 pub unsafe trait Pod: 'static {}
 
+/// Types for which the all zero byte pattern is a valid instance.
+///
+/// The type may contain padding.
+///
+/// # Safety
+///
+/// It must be safe to create an instance of `Self` from all zero bytes.
+///
+/// Implemented for every [`Pod`] type, for `bool`, `char` and the raw pointer types and for tuples of up to 8 fields of the same type.
+pub unsafe trait Zeroable: 'static {}
+
+/// Types for which every byte pattern is a valid instance.
+///
+/// The type may contain padding.
+/// Values can be read out of a [`DataView`] but not written into one.
+///
+/// # Safety
+///
+/// It must be safe to transmute from any byte array (with length equal to the size of the type) to `Self`.
+///
+/// Implemented for every [`Pod`] type and for tuples of up to 8 fields where every field has the same type, such as `(u32, u32)`.
+/// The order of the fields of a tuple in memory is unspecified.
+pub unsafe trait AnyBitPattern: Zeroable {}
+
+/// Types whose values can be viewed as bytes.
+///
+/// Not every byte pattern needs to be a valid instance of the type, but the type must not contain padding.
+/// Values can be written into a [`DataView`] but not read out of one.
+///
+/// # Safety
+///
+/// It must be safe to transmute from `Self` to a byte array (with length equal to the size of the type).
+///
+/// Implemented for every [`Pod`] type and for `bool`, `char`, `str` and the `NonZero` integers.
+pub unsafe trait AsBytes: 'static {}
+
+// Pod is the intersection of the capabilities
+unsafe impl<T: ?Sized + Pod> Zeroable for T {}
+unsafe impl<T: ?Sized + Pod> AnyBitPattern for T {}
+unsafe impl<T: ?Sized + Pod> AsBytes for T {}
+
+unsafe impl Zeroable for bool {}
+unsafe impl Zeroable for char {}
+#[cfg(not(feature = "int2ptr"))]
+unsafe impl<T: 'static> Zeroable for *const T {}
+#[cfg(not(feature = "int2ptr"))]
+unsafe impl<T: 'static> Zeroable for *mut T {}
+
+unsafe impl AsBytes for bool {}
+unsafe impl AsBytes for char {}
+unsafe impl AsBytes for str {}
+unsafe impl AsBytes for num::NonZeroI8 {}
+unsafe impl AsBytes for num::NonZeroI16 {}
+unsafe impl AsBytes for num::NonZeroI32 {}
+unsafe impl AsBytes for num::NonZeroI64 {}
+unsafe impl AsBytes for num::NonZeroI128 {}
+unsafe impl AsBytes for num::NonZeroIsize {}
+unsafe impl AsBytes for num::NonZeroU8 {}
+unsafe impl AsBytes for num::NonZeroU16 {}
+unsafe impl AsBytes for num::NonZeroU32 {}
+unsafe impl AsBytes for num::NonZeroU64 {}
+unsafe impl AsBytes for num::NonZeroU128 {}
+unsafe impl AsBytes for num::NonZeroUsize {}
+
 /// Returns a zero-initialized instance of the type.
 ///
 /// ```
 /// let v: i32 = dataview::zeroed();
 /// assert_eq!(v, 0);
 /// ```
+///
+/// This is a const fn and can initialize constants and statics:
+///
+/// ```
+/// #[derive(dataview::Pod)]
+/// #[repr(C)]
+/// struct State {
+/// 	counters: [u64; 16],
+/// 	flags: u32,
+/// 	mask: u32,
+/// }
+///
+/// static STATE: State = dataview::zeroed();
+/// assert_eq!(STATE.counters, [0; 16]);
+/// ```
 #[inline]
-pub fn zeroed<T: Pod>() -> T {
+pub const fn zeroed<T: Zeroable>() -> T {
 	unsafe { mem::MaybeUninit::zeroed().assume_init() }
 }
 
@@ -90,7 +329,7 @@ pub fn zeroed<T: Pod>() -> T {
 /// assert_eq!(dataview::bytes(&v), &[0xcd, 0xcd, 0xcd, 0xcd]);
 /// ```
 #[inline]
-pub fn bytes<T: ?Sized + Pod>(src: &T) -> &[u8] {
+pub fn bytes<T: ?Sized + AsBytes>(src: &T) -> &[u8] {
 	unsafe { slice::from_raw_parts(src as *const _ as *const u8, mem::size_of_val(src)) }
 }
 
@@ -100,6 +339,142 @@ pub fn bytes_mut<T: ?Sized + Pod>(src: &mut T) -> &mut [u8] {
 	unsafe { slice::from_raw_parts_mut(src as *mut _ as *mut u8, mem::size_of_val(src)) }
 }
 
+/// Reinterprets a byte slice as a reference to the object.
+///
+/// Returns `None` if the length of the bytes does not equal the size of the object or if the bytes are not aligned.
+///
+/// ```
+/// let v = 0xcdcdcdcd_u32;
+/// assert_eq!(dataview::try_from_bytes::<u32>(dataview::bytes(&v)), Some(&v));
+/// assert_eq!(dataview::try_from_bytes::<u16>(dataview::bytes(&v)), None);
+/// ```
+#[inline]
+pub fn try_from_bytes<T: AnyBitPattern>(bytes: &[u8]) -> Option<&T> {
+	let ptr = bytes.as_ptr() as *const T;
+	if bytes.len() != mem::size_of::<T>() || !is_aligned(ptr) {
+		return None;
+	}
+	unsafe { Some(&*ptr) }
+}
+
+/// Reinterprets a mutable byte slice as a mutable reference to the object.
+///
+/// Returns `None` if the length of the bytes does not equal the size of the object or if the bytes are not aligned.
+#[inline]
+pub fn try_from_bytes_mut<T: Pod>(bytes: &mut [u8]) -> Option<&mut T> {
+	let ptr = bytes.as_mut_ptr() as *mut T;
+	if bytes.len() != mem::size_of::<T>() || !is_aligned(ptr) {
+		return None;
+	}
+	unsafe { Some(&mut *ptr) }
+}
+
+/// Reads the object from a byte slice without alignment requirements.
+///
+/// Returns `None` if the length of the bytes does not equal the size of the object.
+///
+/// ```
+/// let bytes = [1, 1, 0, 0, 0];
+/// assert_eq!(dataview::read_unaligned::<u32>(&bytes[1..]), Some(u32::from_ne_bytes([1, 0, 0, 0])));
+/// assert_eq!(dataview::read_unaligned::<u32>(&bytes), None);
+///
+/// const VERSION: Option<u16> = dataview::read_unaligned(b"\x01\x00");
+/// assert_eq!(VERSION, Some(u16::from_le(1)));
+/// ```
+#[inline]
+pub const fn read_unaligned<T: AnyBitPattern>(bytes: &[u8]) -> Option<T> {
+	if bytes.len() != mem::size_of::<T>() {
+		return None;
+	}
+	unsafe { Some(ptr::read_unaligned(bytes.as_ptr() as *const T)) }
+}
+
+/// Writes the object into a byte slice without alignment requirements.
+///
+/// Returns `None` if the length of the bytes does not equal the size of the object.
+#[inline]
+pub fn write_unaligned<T: ?Sized + AsBytes>(bytes: &mut [u8], value: &T) -> Option<()> {
+	let src = self::bytes(value);
+	if bytes.len() != src.len() {
+		return None;
+	}
+	bytes.copy_from_slice(src);
+	Some(())
+}
+
+/// Compares the objects' memory for equality.
+///
+/// Note that this may differ from `PartialEq`, eg. for floating point numbers `-0.0` and `0.0` are not bytewise equal.
+///
+/// ```
+/// assert!(dataview::pod_eq(&[1u16, 2], &[1u16, 2]));
+/// assert!(!dataview::pod_eq(&-0.0f32, &0.0f32));
+/// ```
+#[inline]
+pub fn pod_eq<T: ?Sized + AsBytes>(a: &T, b: &T) -> bool {
+	bytes(a) == bytes(b)
+}
+
+/// Compares the objects' memory lexicographically, like `memcmp`.
+///
+/// ```
+/// use std::cmp::Ordering;
+/// assert_eq!(dataview::pod_cmp(&[1u8, 2], &[1u8, 3]), Ordering::Less);
+/// ```
+#[inline]
+pub fn pod_cmp<T: ?Sized + AsBytes>(a: &T, b: &T) -> cmp::Ordering {
+	bytes(a).cmp(bytes(b))
+}
+
+/// Feeds the objects' memory into a hasher.
+///
+/// Note that this may differ from `Hash`, eg. slices do not hash their length and padding free structs hash their fields' bytes back to back.
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// let mut a = DefaultHasher::new();
+/// dataview::hash_bytes(&[1u16, 2], &mut a);
+///
+/// let mut b = DefaultHasher::new();
+/// dataview::DataView::from(&[1u16, 2]).hash_into(&mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[inline]
+pub fn hash_bytes<T: ?Sized + AsBytes, H: hash::Hasher>(value: &T, state: &mut H) {
+	state.write(bytes(value))
+}
+
+/// Swaps the objects' memory.
+///
+/// # Panics
+///
+/// Panics if the objects are not the same size, which can only happen for unsized types.
+#[track_caller]
+#[inline]
+pub fn pod_swap<T: ?Sized + Pod>(a: &mut T, b: &mut T) {
+	bytes_mut(a).swap_with_slice(bytes_mut(b))
+}
+
+/// Copies the memory of one object into another object of a possibly different type, like `memcpy`.
+///
+/// ```
+/// let mut dst = [0u16; 2];
+/// dataview::copy_bytes(&mut dst, &0xffffffff_u32);
+/// assert_eq!(dst, [0xffff, 0xffff]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the objects are not the same size.
+#[track_caller]
+#[inline]
+pub fn copy_bytes<T: ?Sized + Pod, U: ?Sized + AsBytes>(dst: &mut T, src: &U) {
+	bytes_mut(dst).copy_from_slice(bytes(src))
+}
+
 /// Helper trait to provide methods directly on the pod types.
 ///
 /// Do not use this trait in any signatures, use [`Pod`] directly instead.
@@ -159,20 +534,89 @@ unsafe impl Pod for usize {}
 unsafe impl Pod for f32 {}
 unsafe impl Pod for f64 {}
 
+// All bit patterns are valid thanks to the guaranteed niche optimization, zero is None
+unsafe impl Pod for Option<num::NonZeroI8> {}
+unsafe impl Pod for Option<num::NonZeroI16> {}
+unsafe impl Pod for Option<num::NonZeroI32> {}
+unsafe impl Pod for Option<num::NonZeroI64> {}
+unsafe impl Pod for Option<num::NonZeroI128> {}
+unsafe impl Pod for Option<num::NonZeroIsize> {}
+
+unsafe impl Pod for Option<num::NonZeroU8> {}
+unsafe impl Pod for Option<num::NonZeroU16> {}
+unsafe impl Pod for Option<num::NonZeroU32> {}
+unsafe impl Pod for Option<num::NonZeroU64> {}
+unsafe impl Pod for Option<num::NonZeroU128> {}
+unsafe impl Pod for Option<num::NonZeroUsize> {}
+
 #[cfg(feature = "int2ptr")]
 unsafe impl<T: 'static> Pod for *const T {}
 #[cfg(feature = "int2ptr")]
 unsafe impl<T: 'static> Pod for *mut T {}
 
+// Function pointers are never null, only the Option wrapped function pointers accept every bit pattern
+// Safe function pointers are excluded as calling one read from arbitrary bytes would not require unsafe
+#[cfg(feature = "int2ptr")]
+macro_rules! impl_pod_fn_ptr {
+	($($arg:ident),*) => {
+		unsafe impl<R: 'static, $($arg: 'static),*> Pod for Option<unsafe extern "C" fn($($arg),*) -> R> {}
+	};
+}
+#[cfg(feature = "int2ptr")]
+impl_pod_fn_ptr!();
+#[cfg(feature = "int2ptr")]
+impl_pod_fn_ptr!(A);
+#[cfg(feature = "int2ptr")]
+impl_pod_fn_ptr!(A, B);
+#[cfg(feature = "int2ptr")]
+impl_pod_fn_ptr!(A, B, C);
+#[cfg(feature = "int2ptr")]
+impl_pod_fn_ptr!(A, B, C, D);
+#[cfg(feature = "int2ptr")]
+impl_pod_fn_ptr!(A, B, C, D, E);
+#[cfg(feature = "int2ptr")]
+impl_pod_fn_ptr!(A, B, C, D, E, F);
+
 unsafe impl<T: 'static> Pod for PhantomData<T> {}
 
+unsafe impl<T: Pod> Pod for num::Wrapping<T> {}
+unsafe impl<T: Pod> Pod for num::Saturating<T> {}
+unsafe impl<T: ?Sized + Pod> Pod for mem::ManuallyDrop<T> {}
+
+// Vectors with a non power of two number of lanes are padded
+#[cfg(feature = "simd")]
+macro_rules! impl_pod_simd {
+	($($lanes:literal),*) => {
+		$(unsafe impl<T: Pod + core::simd::SimdElement> Pod for core::simd::Simd<T, $lanes> {})*
+	};
+}
+#[cfg(feature = "simd")]
+impl_pod_simd!(1, 2, 4, 8, 16, 32, 64);
+
 unsafe impl<T: Pod> Pod for [T] {}
 unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
 
+// Tuples are repr(Rust), their fields may be reordered and padded so they can be read but not viewed as bytes
+macro_rules! impl_pod_tuple {
+	($($t:ident),*) => {
+		unsafe impl<T: Zeroable> Zeroable for ($($t,)*) {}
+		unsafe impl<T: AnyBitPattern> AnyBitPattern for ($($t,)*) {}
+	};
+}
+impl_pod_tuple!(T);
+impl_pod_tuple!(T, T);
+impl_pod_tuple!(T, T, T);
+impl_pod_tuple!(T, T, T, T);
+impl_pod_tuple!(T, T, T, T, T);
+impl_pod_tuple!(T, T, T, T, T, T);
+impl_pod_tuple!(T, T, T, T, T, T, T);
+impl_pod_tuple!(T, T, T, T, T, T, T, T);
+
 // Strict provenance approved way of checking raw pointer alignment without exposing the pointer
-const fn is_aligned<T>(ptr: *const T) -> bool {
+#[allow(clippy::transmutes_expressible_as_ptr_casts)]
+fn is_aligned<T>(ptr: *const T) -> bool {
 	let addr: usize = unsafe { mem::transmute(ptr) };
-	addr % mem::align_of::<T>() == 0
+	addr & (mem::align_of::<T>() - 1) == 0
 }
 
 #[cfg(test)]
@@ -181,23 +625,3 @@ mod tests;
 #[cfg(doc)]
 #[doc = include_str!("../readme.md")]
 fn readme() {}
-
-/// Reveals the evaluated value of a constant expression.
-///
-/// The result is a compiletime error: `expected an array with a fixed size of 0 elements, found one with N elements` where `N` is the value of the constant expression.
-///
-/// ```compile_fail
-/// struct Foo {
-/// 	field1: i8,
-/// 	field2: u16,
-/// }
-///
-/// dataview::reveal_const!(std::mem::size_of::<Foo>());
-/// ```
-#[doc(hidden)]
-#[macro_export]
-macro_rules! reveal_const {
-	($e:expr) => {
-		const _: [(); 0] = [(); $e];
-	};
-}
@@ -0,0 +1,107 @@
+use core::{fmt, ops};
+use std::fs::File;
+use std::io;
+use memmap2::{Mmap, MmapMut, MmapOptions};
+use super::*;
+
+/// Memory-mapped file which derefs to [`DataView`].
+///
+/// The file is paged in on demand, multi-gigabyte files can be parsed without reading them into memory.
+///
+/// ```no_run
+/// use std::fs::File;
+/// use dataview::MappedView;
+///
+/// let file = File::open("data.bin")?;
+/// // Safety: The file must not be modified while it is mapped
+/// let view = unsafe { MappedView::map(&file)? };
+/// let magic: [u8; 4] = view.read(0);
+///
+/// // Writes to a copy-on-write mapping are private to this process
+/// let mut patched = unsafe { MappedView::map_copy(&file)? };
+/// patched.as_data_view_mut().unwrap().write(0, b"TEMP");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Safety
+///
+/// Mapping a file is unsafe because other processes may modify or truncate the file while it is mapped,
+/// see [`memmap2::Mmap`] for details.
+pub struct MappedView {
+	map: Map,
+}
+
+enum Map {
+	ReadOnly(Mmap),
+	CopyOnWrite(MmapMut),
+}
+
+impl MappedView {
+	/// Maps the file read-only.
+	///
+	/// # Safety
+	///
+	/// The file must not be modified or truncated while it is mapped, see [`memmap2::Mmap`].
+	#[inline]
+	pub unsafe fn map(file: &File) -> io::Result<MappedView> {
+		let map = Mmap::map(file)?;
+		Ok(MappedView { map: Map::ReadOnly(map) })
+	}
+	/// Maps the file copy-on-write.
+	///
+	/// Writes are visible only to this mapping and are never written back to the file.
+	///
+	/// # Safety
+	///
+	/// The file must not be modified or truncated while it is mapped, see [`memmap2::Mmap`].
+	#[inline]
+	pub unsafe fn map_copy(file: &File) -> io::Result<MappedView> {
+		let map = MmapOptions::new().map_copy(file)?;
+		Ok(MappedView { map: Map::CopyOnWrite(map) })
+	}
+	/// Returns true if the mapping is copy-on-write.
+	#[inline]
+	pub fn is_copy_on_write(&self) -> bool {
+		matches!(self.map, Map::CopyOnWrite(_))
+	}
+	/// Returns the data view over the mapped bytes.
+	#[inline]
+	pub fn as_data_view(&self) -> &DataView {
+		match &self.map {
+			Map::ReadOnly(map) => DataView::from(&map[..]),
+			Map::CopyOnWrite(map) => DataView::from(&map[..]),
+		}
+	}
+	/// Returns a mutable data view over the mapped bytes if the mapping is copy-on-write.
+	#[inline]
+	pub fn as_data_view_mut(&mut self) -> Option<&mut DataView> {
+		match &mut self.map {
+			Map::ReadOnly(_) => None,
+			Map::CopyOnWrite(map) => Some(DataView::from_mut(&mut map[..])),
+		}
+	}
+}
+
+impl ops::Deref for MappedView {
+	type Target = DataView;
+	#[inline]
+	fn deref(&self) -> &DataView {
+		self.as_data_view()
+	}
+}
+
+impl AsRef<[u8]> for MappedView {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		self.as_data_view().as_ref()
+	}
+}
+
+impl fmt::Debug for MappedView {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("MappedView")
+			.field("len", &self.len())
+			.field("copy_on_write", &self.is_copy_on_write())
+			.finish()
+	}
+}
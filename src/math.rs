@@ -0,0 +1,56 @@
+// Pod impls for third party math types
+//
+// Every type here is `#[repr(C)]` (or a SIMD register) with fields of a single Pod type and no padding.
+
+use super::*;
+
+#[cfg(feature = "mint")]
+mod mint_impls {
+	use super::*;
+
+	macro_rules! impl_mint_pod {
+		($($ty:ident)*) => {
+			$(unsafe impl<T: Pod> Pod for mint::$ty<T> {})*
+		};
+	}
+
+	impl_mint_pod! {
+		Vector2 Vector3 Vector4 Point2 Point3 Quaternion
+		RowMatrix2 RowMatrix2x3 RowMatrix2x4 RowMatrix3x2 RowMatrix3 RowMatrix3x4 RowMatrix4x2 RowMatrix4x3 RowMatrix4
+		ColumnMatrix2 ColumnMatrix2x3 ColumnMatrix2x4 ColumnMatrix3x2 ColumnMatrix3 ColumnMatrix3x4 ColumnMatrix4x2 ColumnMatrix4x3 ColumnMatrix4
+	}
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+	use super::*;
+
+	macro_rules! impl_glam_pod {
+		($($ty:ident)*) => {
+			$(unsafe impl Pod for glam::$ty {})*
+		};
+	}
+
+	impl_glam_pod! {
+		Vec2 Vec3 Vec4 Quat Mat2 Mat3 Mat4
+		DVec2 DVec3 DVec4 DQuat DMat2 DMat3 DMat4 DAffine2 DAffine3
+		I8Vec2 I8Vec3 I8Vec4 U8Vec2 U8Vec3 U8Vec4
+		I16Vec2 I16Vec3 I16Vec4 U16Vec2 U16Vec3 U16Vec4
+		IVec2 IVec3 IVec4 UVec2 UVec3 UVec4
+		I64Vec2 I64Vec3 I64Vec4 U64Vec2 U64Vec3 U64Vec4
+	}
+
+	// The SIMD aligned types may contain padding depending on the target, they can be read but not viewed as bytes
+	macro_rules! impl_glam_any_bit_pattern {
+		($($ty:ident)*) => {
+			$(
+				unsafe impl Zeroable for glam::$ty {}
+				unsafe impl AnyBitPattern for glam::$ty {}
+			)*
+		};
+	}
+
+	impl_glam_any_bit_pattern! {
+		Vec3A Mat3A Affine2 Affine3A
+	}
+}
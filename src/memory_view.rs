@@ -0,0 +1,230 @@
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// Read access to a byte addressable memory backend.
+///
+/// Implement [`read_bytes`](MemoryView::read_bytes) for backends such as process memory readers, paged caches or MMIO windows
+/// and code generic over `MemoryView` works with them as well as with [`DataView`].
+///
+/// ```
+/// use dataview::MemoryView;
+///
+/// // A backend which is not contiguous in memory
+/// struct Pages<'a>(&'a [[u8; 4]]);
+///
+/// impl MemoryView for Pages<'_> {
+/// 	fn len(&self) -> usize {
+/// 		self.0.len() * 4
+/// 	}
+/// 	fn read_bytes(&self, offset: usize, dest: &mut [u8]) -> Option<()> {
+/// 		if offset.checked_add(dest.len())? > self.len() {
+/// 			return None;
+/// 		}
+/// 		for (i, byte) in dest.iter_mut().enumerate() {
+/// 			*byte = self.0[(offset + i) / 4][(offset + i) % 4];
+/// 		}
+/// 		Some(())
+/// 	}
+/// }
+///
+/// fn read_header<M: ?Sized + MemoryView>(memory: &M) -> Option<[u16; 2]> {
+/// 	memory.try_read(2)
+/// }
+///
+/// let pages = Pages(&[[0, 0, 1, 0], [2, 0, 0, 0]]);
+/// let flat = [0u8, 0, 1, 0, 2, 0, 0, 0];
+/// assert_eq!(read_header(&pages), read_header(dataview::DataView::from(&flat)));
+/// assert_eq!(pages.try_get::<u32>(0), None);
+/// ```
+///
+/// The *Panicking* methods panic with the message `"invalid offset"`, see [`DataView`].
+pub trait MemoryView {
+	/// Returns the number of addressable bytes.
+	fn len(&self) -> usize;
+
+	/// Returns true if there are no addressable bytes.
+	#[inline]
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Copies bytes starting at the offset into the destination.
+	///
+	/// Returns `None` if the range is out of bounds or cannot be read.
+	fn read_bytes(&self, offset: usize, dest: &mut [u8]) -> Option<()>;
+
+	/// Returns the memory as a contiguous data view if the backend supports it.
+	///
+	/// Enables the methods returning references, the default implementation returns `None`.
+	#[inline]
+	fn as_data_view(&self) -> Option<&DataView> {
+		None
+	}
+
+	/// Reads a (potentially unaligned) value.
+	#[inline]
+	fn try_read<T: AnyBitPattern>(&self, offset: usize) -> Option<T> {
+		// Zeroed memory has its padding bytes initialized as well, it can be viewed as bytes
+		let mut value = mem::MaybeUninit::<T>::zeroed();
+		let dest = unsafe { slice::from_raw_parts_mut(value.as_mut_ptr() as *mut u8, mem::size_of::<T>()) };
+		self.read_bytes(offset, dest)?;
+		Some(unsafe { value.assume_init() })
+	}
+	/// Reads a (potentially unaligned) value.
+	#[track_caller]
+	#[inline]
+	fn read<T: AnyBitPattern>(&self, offset: usize) -> T {
+		match self.try_read(offset) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+
+	/// Reads a (potentially unaligned) value into the destination.
+	#[inline]
+	fn try_read_into<T: ?Sized + Pod>(&self, offset: usize, dest: &mut T) -> Option<()> {
+		self.read_bytes(offset, bytes_mut(dest))
+	}
+	/// Reads a (potentially unaligned) value into the destination.
+	#[track_caller]
+	#[inline]
+	fn read_into<T: ?Sized + Pod>(&self, offset: usize, dest: &mut T) {
+		match self.try_read_into(offset, dest) {
+			Some(()) => (),
+			None => invalid_offset(),
+		}
+	}
+
+	/// Gets an aligned reference, requires [`as_data_view`](MemoryView::as_data_view).
+	#[inline]
+	fn try_get<T: AnyBitPattern>(&self, offset: usize) -> Option<&T> {
+		self.as_data_view()?.try_get(offset)
+	}
+	/// Gets an aligned reference, requires [`as_data_view`](MemoryView::as_data_view).
+	#[track_caller]
+	#[inline]
+	fn get<T: AnyBitPattern>(&self, offset: usize) -> &T {
+		match self.try_get(offset) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+
+	/// Gets an aligned slice, requires [`as_data_view`](MemoryView::as_data_view).
+	#[inline]
+	fn try_slice<T: AnyBitPattern>(&self, offset: usize, len: usize) -> Option<&[T]> {
+		self.as_data_view()?.try_slice(offset, len)
+	}
+	/// Gets an aligned slice, requires [`as_data_view`](MemoryView::as_data_view).
+	#[track_caller]
+	#[inline]
+	fn slice<T: AnyBitPattern>(&self, offset: usize, len: usize) -> &[T] {
+		match self.try_slice(offset, len) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
+
+/// Write access to a byte addressable memory backend.
+pub trait MemoryViewMut: MemoryView {
+	/// Copies the bytes to the memory starting at the offset.
+	///
+	/// Returns `None` if the range is out of bounds or cannot be written.
+	fn write_bytes(&mut self, offset: usize, src: &[u8]) -> Option<()>;
+
+	/// Writes a value.
+	#[inline]
+	fn try_write<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) -> Option<()> {
+		self.write_bytes(offset, bytes(value))
+	}
+	/// Writes a value.
+	#[track_caller]
+	#[inline]
+	fn write<T: ?Sized + AsBytes>(&mut self, offset: usize, value: &T) {
+		match self.try_write(offset, value) {
+			Some(()) => (),
+			None => invalid_offset(),
+		}
+	}
+}
+
+impl MemoryView for DataView {
+	#[inline]
+	fn len(&self) -> usize {
+		DataView::len(self)
+	}
+	#[inline]
+	fn read_bytes(&self, offset: usize, dest: &mut [u8]) -> Option<()> {
+		let src = self.as_ref().get(offset..offset.checked_add(dest.len())?)?;
+		dest.copy_from_slice(src);
+		Some(())
+	}
+	#[inline]
+	fn as_data_view(&self) -> Option<&DataView> {
+		Some(self)
+	}
+}
+impl MemoryViewMut for DataView {
+	#[inline]
+	fn write_bytes(&mut self, offset: usize, src: &[u8]) -> Option<()> {
+		DataView::try_write(self, offset, src)
+	}
+}
+
+impl MemoryView for [u8] {
+	#[inline]
+	fn len(&self) -> usize {
+		<[u8]>::len(self)
+	}
+	#[inline]
+	fn read_bytes(&self, offset: usize, dest: &mut [u8]) -> Option<()> {
+		DataView::from(self).read_bytes(offset, dest)
+	}
+	#[inline]
+	fn as_data_view(&self) -> Option<&DataView> {
+		Some(DataView::from(self))
+	}
+}
+impl MemoryViewMut for [u8] {
+	#[inline]
+	fn write_bytes(&mut self, offset: usize, src: &[u8]) -> Option<()> {
+		DataView::from_mut(self).write_bytes(offset, src)
+	}
+}
+
+impl<const N: usize> MemoryView for FixedView<N> {
+	#[inline]
+	fn len(&self) -> usize {
+		N
+	}
+	#[inline]
+	fn read_bytes(&self, offset: usize, dest: &mut [u8]) -> Option<()> {
+		(**self).read_bytes(offset, dest)
+	}
+	#[inline]
+	fn as_data_view(&self) -> Option<&DataView> {
+		Some(self)
+	}
+}
+impl<const N: usize> MemoryViewMut for FixedView<N> {
+	#[inline]
+	fn write_bytes(&mut self, offset: usize, src: &[u8]) -> Option<()> {
+		(**self).write_bytes(offset, src)
+	}
+}
+
+impl<M: ?Sized + MemoryView> MemoryView for &M {
+	#[inline]
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+	#[inline]
+	fn read_bytes(&self, offset: usize, dest: &mut [u8]) -> Option<()> {
+		(**self).read_bytes(offset, dest)
+	}
+	#[inline]
+	fn as_data_view(&self) -> Option<&DataView> {
+		(**self).as_data_view()
+	}
+}
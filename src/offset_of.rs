@@ -13,13 +13,53 @@
 ///
 /// const OFFSET: usize = dataview::offset_of!(Data.float);
 /// assert_eq!(OFFSET, 4);
+///
+/// let padding = [0u8; dataview::offset_of!(Data.float)];
+/// assert_eq!(padding.len(), 4);
 /// ```
 ///
 /// The syntax is `$ty.$field`.
 ///
-/// No support for tuples, tuple structs or unions.
+/// The offset is computed by `core::mem::offset_of!` and can be used in constants, array lengths and const generic arguments, also for generic types.
+/// Compilers older than Rust 1.77 fall back to projecting a raw pointer to the field, which cannot name the generic parameters of the surrounding function.
 ///
-/// No support for projecting through multiple fields.
+/// Fields of `#[repr(packed)]` structs are supported.
+/// No reference to a field is ever created, only raw pointers to uninitialized memory which are never read from.
+/// Taking a reference to a misaligned field would be a compile error.
+///
+/// Nested fields are supported with the syntax `$ty.$field.$nested` since Rust 1.82, returning the offset of the nested field from the start of the type:
+///
+/// ```
+/// #[repr(C)]
+/// struct Inner {
+/// 	x: u16,
+/// 	y: u16,
+/// }
+/// #[repr(C)]
+/// struct Outer {
+/// 	tag: u32,
+/// 	inner: Inner,
+/// }
+///
+/// assert_eq!(dataview::offset_of!(Outer.inner.y), 6);
+/// ```
+///
+/// The offset of an element in an array field is returned with the syntax `$ty.$field[$index]`.
+/// The index is checked against the length of the array, which is a compile error when used in a constant:
+///
+/// ```
+/// #[repr(C)]
+/// struct Table {
+/// 	len: u32,
+/// 	entries: [u64; 4],
+/// }
+///
+/// assert_eq!(dataview::offset_of!(Table.entries[3]), 32);
+/// ```
+///
+/// The type may be any type path, including generic arguments, qualified paths and type aliases, eg. `offset_of!(Foo<Bar<u8>>.field)`.
+///
+/// No support for tuples, tuple structs or unions.
 #[macro_export]
 macro_rules! offset_of {
 	($($tt:tt)*) => {
@@ -30,11 +70,49 @@ macro_rules! offset_of {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __offset_of {
-	([$($ty:tt)*] . $($field:ident)?) => {{
+	([$($ty:tt)*] . $field:ident $(. $nested:ident)* [$index:expr]) => {{
+		// Resolves the fields first which protects the projection below from Deref
+		let offset = $crate::offset_of!($($ty)* . $field $(. $nested)*);
+		let uninit = ::core::mem::MaybeUninit::<$($ty)*>::uninit();
+		let uninit_ptr = uninit.as_ptr();
+		#[allow(unused_unsafe)]
+		let array_ptr = unsafe { ::core::ptr::addr_of!((*uninit_ptr).$field $(.$nested)*) };
+		offset + $crate::__array_offset(array_ptr, $index)
+	}};
+	([$($ty:tt)*] . $field:ident $(. $nested:ident)*) => {
+		$crate::__field_offset!([$($ty)*] $field $(. $nested)*)
+	};
+	([$($ty:tt)*] . $($field:tt)?) => {
+		compile_error!("offset of tuple field not supported")
+	};
+	([$($ty:tt)*] $tt:tt $($tail:tt)*) => {
+		$crate::__offset_of!([$($ty)* $tt] $($tail)*)
+	};
+	([$($ty:tt)*]) => {
+		compile_error!("missing field access")
+	};
+}
+
+#[cfg(not(dataview_no_offset_of))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __field_offset {
+	([$($ty:tt)*] $field:ident $(. $nested:ident)*) => {
+		// The compiler resolves the fields without going through Deref
+		::core::mem::offset_of!($($ty)*, $field $(.$nested)*)
+	};
+}
+
+// Fallback for compilers without `core::mem::offset_of!`, detected by the build script
+#[cfg(dataview_no_offset_of)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __field_offset {
+	([$($ty:tt)*] $field:ident) => {{
 		type Ty = $($ty)*;
 		// Assert that field exists on the type
 		// This prevents auto-Deref from causing UB
-		let Ty { $($field)?: _, .. };
+		let Ty { $field: _, .. };
 		// Use MaybeUninit as the subject of the field offset
 		let uninit = ::core::mem::MaybeUninit::<Ty>::uninit();
 		let uninit_ptr = uninit.as_ptr();
@@ -43,21 +121,32 @@ macro_rules! __offset_of {
 		// Hope that's enough to keep the code safe
 		#[allow(unused_unsafe)]
 		unsafe {
-			let field_ptr = ::core::ptr::addr_of!((*uninit_ptr).$($field)?);
+			let field_ptr = ::core::ptr::addr_of!((*uninit_ptr).$field);
 			(field_ptr as *const u8).offset_from(uninit_ptr as *const u8) as usize
 		}
 	}};
-	([$($ty:tt)*] . $($field:tt)?) => {
-		compile_error!("offset of tuple field not supported")
-	};
-	([$($ty:tt)*] $tt:tt $($tail:tt)*) => {
-		$crate::__offset_of!([$($ty)* $tt] $($tail)*)
-	};
-	([$($ty:tt)*]) => {
-		compile_error!("missing field access")
+	([$($ty:tt)*] $field:ident $(. $nested:ident)+) => {
+		// Nested fields are left to the compiler
+		::core::mem::offset_of!($($ty)*, $field $(.$nested)+)
 	};
 }
 
+// Offset of the element in the array, accepts only arrays to prevent Deref
+#[doc(hidden)]
+#[track_caller]
+pub const fn __array_offset<T, const N: usize>(_: *const [T; N], index: usize) -> usize {
+	if index >= N {
+		panic!("array index out of bounds");
+	}
+	index * ::core::mem::size_of::<T>()
+}
+
+// Size of the field behind the pointer
+#[doc(hidden)]
+pub const fn __field_size<T>(_: *const T) -> usize {
+	::core::mem::size_of::<T>()
+}
+
 /// Returns the `start..end` offsets of a field.
 ///
 /// ```
@@ -78,6 +167,35 @@ macro_rules! __offset_of {
 ///
 /// The syntax is `$ty.$field`.
 ///
+/// The span of multiple fields is returned with the syntax `$ty.$from ..= $ty.$to`, from the start of the first field to the end of the last field.
+/// With the syntax `$ty.$from .. $ty.$to` the span ends at the start of the last field instead:
+///
+/// ```
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: u32,
+/// 	version: u16,
+/// 	flags: u16,
+/// 	crc: u32,
+/// }
+///
+/// assert_eq!(dataview::span_of!(Header.magic ..= Header.flags), 0..8);
+/// assert_eq!(dataview::span_of!(Header.version .. Header.crc), 4..8);
+/// ```
+///
+/// The span from the start of a field to the end of the type is returned with the syntax `$ty.$from ..`:
+///
+/// ```
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: u32,
+/// 	len: u16,
+/// 	flags: u16,
+/// }
+///
+/// assert_eq!(dataview::span_of!(Header.len ..), 4..8);
+/// ```
+///
 /// No support for tuples, tuple structs or unions.
 ///
 /// No support for projecting through multiple fields.
@@ -91,24 +209,32 @@ macro_rules! span_of {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __span_of {
-	([$($ty:tt)*] . $($field:ident)?) => {{
-		type Ty = $($ty)*;
-		// Assert that field exists on the type
-		// This prevents auto-Deref from causing UB
-		let Ty { $($field)?: _, .. };
-		// Use MaybeUninit as the subject of the field offset
-		let uninit = ::core::mem::MaybeUninit::<Ty>::uninit();
+	([$($ty:tt)*] . $from:ident ..= $($tail:tt)+) => {
+		$crate::__span_of!(@range end [$($ty)*] $from [] $($tail)+)
+	};
+	([$($ty:tt)*] . $from:ident ..) => {
+		$crate::__span_of!([$($ty)*] . $from).start..::core::mem::size_of::<$($ty)*>()
+	};
+	([$($ty:tt)*] . $from:ident .. $($tail:tt)+) => {
+		$crate::__span_of!(@range start [$($ty)*] $from [] $($tail)+)
+	};
+	(@range $bound:ident [$($ty:tt)*] $from:ident [$($to_ty:tt)*] . $to:ident) => {{
+		// Both fields must be of the same type
+		let _: ::core::marker::PhantomData<$($ty)*> = ::core::marker::PhantomData::<$($to_ty)*>;
+		$crate::__span_of!([$($ty)*] . $from).start..$crate::__span_of!([$($ty)*] . $to).$bound
+	}};
+	(@range $bound:ident $ty:tt $from:ident [$($to_ty:tt)*] $tt:tt $($tail:tt)*) => {
+		$crate::__span_of!(@range $bound $ty $from [$($to_ty)* $tt] $($tail)*)
+	};
+	([$($ty:tt)*] . $field:ident) => {{
+		// Resolves the field first which protects the projection below from Deref
+		let start = $crate::__field_offset!([$($ty)*] $field);
+		// Project the resolved field to find its size
+		let uninit = ::core::mem::MaybeUninit::<$($ty)*>::uninit();
 		let uninit_ptr = uninit.as_ptr();
-		// We've asserted that the field exists on the type
-		// No Deref coercion or dereferencing a reference
-		// Hope that's enough to keep the code safe
 		#[allow(unused_unsafe)]
-		unsafe {
-			let field_ptr = ::core::ptr::addr_of!((*uninit_ptr).$($field)?);
-			let start = (field_ptr as *const u8).offset_from(uninit_ptr as *const u8) as usize;
-			let end = (field_ptr.offset(1) as *const u8).offset_from(uninit_ptr as *const u8) as usize;
-			start..end
-		}
+		let field_ptr = unsafe { ::core::ptr::addr_of!((*uninit_ptr).$field) };
+		start..start + $crate::__field_size(field_ptr)
 	}};
 	([$($ty:tt)*] . $($field:tt)?) => {
 		compile_error!("offset of tuple field not supported")
@@ -130,6 +256,134 @@ fn nested_fields() {
 	assert_eq!(span_of!(Foo<i32>.value), 4..8);
 }
 
+#[cfg(not(dataview_no_offset_of))]
+#[test]
+fn const_contexts() {
+	#[repr(C)]
+	struct Foo<T> { byte: u8, value: T }
+	struct Buffer<const N: usize>([u8; N]);
+
+	fn generic<T>() -> core::ops::Range<usize> {
+		offset_of!(Foo<T>.value)..span_of!(Foo<T>.value).end
+	}
+	assert_eq!(generic::<u16>(), 2..4);
+
+	let prefix: [u8; offset_of!(Foo<u64>.value)] = [0; 8];
+	let buffer = Buffer::<{ span_of!(Foo<u32>.value).end }>([0; 8]);
+	assert_eq!(prefix.len(), buffer.0.len());
+}
+
+#[test]
+fn projected_fields() {
+	#[repr(C)]
+	struct Inner { a: u8, b: [u16; 3] }
+	#[repr(C)]
+	struct Middle { tag: u32, inner: Inner }
+	#[repr(C)]
+	struct Outer<T> { value: T, middle: Middle }
+
+	assert_eq!(offset_of!(Middle.inner.b), 6);
+	assert_eq!(offset_of!(Outer<u64>.middle.inner.b), 14);
+	const OFFSET: usize = offset_of!(Outer<u8>.middle.inner.a);
+	assert_eq!(OFFSET, 8);
+}
+
+#[test]
+fn field_ranges() {
+	#[repr(C)]
+	struct Header<T> { magic: u32, version: u16, flags: u16, crc: T }
+
+	assert_eq!(span_of!(Header<u32>.magic ..= Header<u32>.flags), 0..8);
+	assert_eq!(span_of!(Header<u32>.magic .. Header<u32>.crc), 0..8);
+	assert_eq!(span_of!(Header<u64>.flags ..= Header<u64>.crc), 6..16);
+	assert_eq!(span_of!(Header<u8>.version ..= Header<u8>.version), 4..6);
+	const SPAN: core::ops::Range<usize> = span_of!(Header<u8>.version .. Header<u8>.crc);
+	assert_eq!(SPAN, 4..8);
+
+	// Includes the trailing padding
+	assert_eq!(span_of!(Header<u64>.crc ..), 8..16);
+	assert_eq!(span_of!(Header<u8>.flags ..), 6..12);
+	const REST: core::ops::Range<usize> = span_of!(Header<u16>.version ..);
+	assert_eq!(REST, 4..12);
+}
+
+#[cfg(test)]
+mod types {
+	#[repr(C)]
+	pub struct Wrap<T> { pub tag: u8, pub value: T }
+	#[repr(C)]
+	pub struct Fixed<const N: usize> { pub len: u16, pub data: [u8; N], pub tail: u32 }
+	pub trait Assoc { type Ty; }
+	impl Assoc for u8 { type Ty = Wrap<u64>; }
+}
+
+#[test]
+fn type_syntax() {
+	use self::types::{Assoc, Fixed, Wrap};
+	type Alias = Wrap<Wrap<u16>>;
+
+	assert_eq!(offset_of!(Wrap<Wrap<u8>>.value), 1);
+	assert_eq!(offset_of!(Wrap<Wrap<Wrap<u32>>>.value.value.value), 12);
+	assert_eq!(offset_of!(self::types::Wrap<u32>.value), 4);
+	assert_eq!(offset_of!(crate::offset_of::types::Wrap::<u16>.value), 2);
+	assert_eq!(offset_of!(Alias.value.value), 4);
+	assert_eq!(offset_of!(<u8 as Assoc>::Ty.value), 8);
+	assert_eq!(offset_of!(Fixed<{ 2 + 1 }>.tail), 8);
+	assert_eq!(offset_of!(Wrap<[u16; 3]>.value[2]), 6);
+	assert_eq!(offset_of!(Wrap<(u8, u32)>.value), 4);
+
+	assert_eq!(span_of!(Wrap<Wrap<u8>>.value), 1..3);
+	assert_eq!(span_of!(<u8 as Assoc>::Ty.value), 8..16);
+	assert_eq!(span_of!(Alias.tag ..= Alias.value), 0..6);
+	assert_eq!(span_of!(Fixed<5>.data ..), 2..12);
+	assert_eq!(span_of!(Fixed<{ 2 * 2 }>.len .. Fixed<{ 2 * 2 }>.tail), 0..8);
+}
+
+#[test]
+fn packed_fields() {
+	#[repr(C, packed)]
+	struct Inner { a: u8, b: [u32; 2] }
+	#[repr(C, packed)]
+	struct Packed { byte: u8, long: u64, inner: Inner, short: u16 }
+	#[repr(C, packed(2))]
+	struct Packed2 { byte: u8, long: u64 }
+
+	assert_eq!(offset_of!(Packed.long), 1);
+	assert_eq!(offset_of!(Packed.inner.b), 10);
+	assert_eq!(offset_of!(Packed.inner.b[1]), 14);
+	assert_eq!(span_of!(Packed.long), 1..9);
+	assert_eq!(span_of!(Packed.inner), 9..18);
+	assert_eq!(span_of!(Packed.long ..= Packed.short), 1..20);
+	assert_eq!(span_of!(Packed2.long), 2..10);
+	const OFFSET: usize = offset_of!(Packed.short);
+	assert_eq!(OFFSET, 18);
+}
+
+#[test]
+fn array_elements() {
+	#[repr(C)]
+	struct Inner { a: u8, b: [u16; 3] }
+	#[repr(C)]
+	struct Data { tag: u32, arr: [u32; 4], inner: Inner }
+
+	assert_eq!(offset_of!(Data.arr[0]), 4);
+	assert_eq!(offset_of!(Data.arr[3]), 16);
+	assert_eq!(offset_of!(Data.inner.b[2]), 26);
+	let index = 1;
+	assert_eq!(offset_of!(Data.arr[index + 1]), 12);
+	const OFFSET: usize = offset_of!(Data.inner.b[1]);
+	assert_eq!(OFFSET, 24);
+}
+
+#[test]
+#[should_panic]
+fn array_out_of_bounds() {
+	#[repr(C)]
+	struct Data { arr: [u32; 4] }
+	let index = 4;
+	let _ = offset_of!(Data.arr[index]);
+}
+
 #[cfg(doc)]
 /**
 ```compile_fail
@@ -156,3 +410,35 @@ let _ = dataview::offset_of!(Subject.target);
 ```
 */
 fn deref_protection() {}
+
+#[cfg(doc)]
+/**
+```compile_fail
+struct Inner {
+	x: u32,
+}
+struct Outer {
+	inner: Box<Inner>,
+}
+let _ = dataview::offset_of!(Outer.inner.x);
+```
+*/
+fn nested_deref_protection() {}
+
+#[cfg(doc)]
+/**
+```compile_fail
+#[repr(C)]
+struct A {
+	x: u32,
+	y: u32,
+}
+#[repr(C)]
+struct B {
+	x: u32,
+	y: u32,
+}
+let _ = dataview::span_of!(A.x ..= B.y);
+```
+*/
+fn span_range_types() {}
@@ -0,0 +1,229 @@
+use core::{cmp, marker::PhantomData, ops};
+use super::*;
+
+// The accessors live on the sealed trait so they cannot be named outside the crate
+// They can still be reached through a generic `P: BoundsPolicy` and are unsafe for that reason:
+// with the unchecked policy every access must be in bounds, which only PolicyView's unsafe constructor establishes
+mod private {
+	use super::*;
+	pub trait Sealed {
+		unsafe fn read<T: Pod>(view: &DataView, offset: usize) -> T;
+		unsafe fn read_into<T: ?Sized + Pod>(view: &DataView, offset: usize, dest: &mut T);
+		unsafe fn write<T: ?Sized + Pod>(view: &mut DataView, offset: usize, value: &T);
+	}
+}
+
+/// Bounds checking policy of a [`PolicyView`].
+///
+/// This trait is sealed, the available policies are [`Checked`], [`ZeroFill`] and [`TrustedUnchecked`].
+/// The policies can only be used through a [`PolicyView`]:
+///
+/// ```compile_fail
+/// use dataview::{BoundsPolicy, DataView, TrustedUnchecked};
+///
+/// let view = DataView::from(&[0u8; 1]);
+/// let _ = <TrustedUnchecked as BoundsPolicy>::read::<u64>(view, 1 << 20);
+/// ```
+///
+/// ```compile_fail
+/// use dataview::{DataView, TrustedUnchecked};
+///
+/// let view = DataView::from(&[0u8; 1]);
+/// let _ = TrustedUnchecked::read::<u64>(view, 1 << 20);
+/// ```
+///
+/// ```compile_fail
+/// use dataview::{BoundsPolicy, DataView, TrustedUnchecked};
+///
+/// fn read<P: BoundsPolicy>(view: &DataView) -> u64 {
+/// 	P::read(view, 1 << 20)
+/// }
+/// let _ = read::<TrustedUnchecked>(DataView::from(&[0u8; 1]));
+/// ```
+pub trait BoundsPolicy: private::Sealed + 'static {}
+
+/// Out of bounds accesses panic, same as the [`DataView`] methods without prefix or suffix.
+pub enum Checked {}
+
+/// Out of bounds accesses are clamped to the view.
+///
+/// Bytes outside the view read as zero and writes outside the view are discarded.
+pub enum ZeroFill {}
+
+/// Accesses are not checked at all, same as the `_unchecked` [`DataView`] methods.
+///
+/// A view with this policy can only be constructed with an unsafe constructor.
+pub enum TrustedUnchecked {}
+
+impl BoundsPolicy for Checked {}
+impl private::Sealed for Checked {
+	#[track_caller]
+	#[inline]
+	unsafe fn read<T: Pod>(view: &DataView, offset: usize) -> T {
+		view.read(offset)
+	}
+	#[track_caller]
+	#[inline]
+	unsafe fn read_into<T: ?Sized + Pod>(view: &DataView, offset: usize, dest: &mut T) {
+		view.read_into(offset, dest)
+	}
+	#[track_caller]
+	#[inline]
+	unsafe fn write<T: ?Sized + Pod>(view: &mut DataView, offset: usize, value: &T) {
+		view.write(offset, value)
+	}
+}
+
+impl BoundsPolicy for ZeroFill {}
+impl private::Sealed for ZeroFill {
+	#[inline]
+	unsafe fn read<T: Pod>(view: &DataView, offset: usize) -> T {
+		match view.try_read(offset) {
+			Some(value) => value,
+			None => {
+				let mut value = zeroed();
+				ZeroFill::read_into(view, offset, &mut value);
+				value
+			},
+		}
+	}
+	#[inline]
+	unsafe fn read_into<T: ?Sized + Pod>(view: &DataView, offset: usize, dest: &mut T) {
+		let dest = bytes_mut(dest);
+		let src = view.as_ref().get(offset..).unwrap_or(&[]);
+		let len = cmp::min(src.len(), dest.len());
+		dest[..len].copy_from_slice(&src[..len]);
+		dest[len..].fill(0);
+	}
+	#[inline]
+	unsafe fn write<T: ?Sized + Pod>(view: &mut DataView, offset: usize, value: &T) {
+		let src = bytes(value);
+		let dest = view.as_mut().get_mut(offset..).unwrap_or(&mut []);
+		let len = cmp::min(src.len(), dest.len());
+		dest[..len].copy_from_slice(&src[..len]);
+	}
+}
+
+impl BoundsPolicy for TrustedUnchecked {}
+impl private::Sealed for TrustedUnchecked {
+	#[inline]
+	unsafe fn read<T: Pod>(view: &DataView, offset: usize) -> T {
+		unsafe { view.read_unchecked(offset) }
+	}
+	#[inline]
+	unsafe fn read_into<T: ?Sized + Pod>(view: &DataView, offset: usize, dest: &mut T) {
+		unsafe { view.read_into_unchecked(offset, dest) }
+	}
+	#[inline]
+	unsafe fn write<T: ?Sized + Pod>(view: &mut DataView, offset: usize, value: &T) {
+		unsafe { view.write_unchecked(offset, value) }
+	}
+}
+
+/// Data view with a bounds checking policy.
+///
+/// Lets a hot loop opt out of bounds checks in one place instead of sprinkling `_unchecked` calls.
+/// Derefs to [`DataView`] for the regular checked API.
+///
+/// ```
+/// use dataview::{DataView, PolicyView, ZeroFill};
+///
+/// let view = DataView::from(&[1u8, 2, 3]);
+/// let view = PolicyView::<ZeroFill>::new(view);
+/// assert_eq!(view.read::<[u8; 4]>(1), [2, 3, 0, 0]);
+/// ```
+#[repr(transparent)]
+pub struct PolicyView<P: BoundsPolicy> {
+	_policy: PhantomData<P>,
+	view: DataView,
+}
+
+impl PolicyView<Checked> {
+	/// Returns a view with the checked policy.
+	#[inline]
+	pub fn new(view: &DataView) -> &PolicyView<Checked> {
+		unsafe { PolicyView::cast(view) }
+	}
+	/// Returns a mutable view with the checked policy.
+	#[inline]
+	pub fn new_mut(view: &mut DataView) -> &mut PolicyView<Checked> {
+		unsafe { PolicyView::cast_mut(view) }
+	}
+}
+
+impl PolicyView<ZeroFill> {
+	/// Returns a view with the zero fill policy.
+	#[inline]
+	pub fn new(view: &DataView) -> &PolicyView<ZeroFill> {
+		unsafe { PolicyView::cast(view) }
+	}
+	/// Returns a mutable view with the zero fill policy.
+	#[inline]
+	pub fn new_mut(view: &mut DataView) -> &mut PolicyView<ZeroFill> {
+		unsafe { PolicyView::cast_mut(view) }
+	}
+}
+
+impl PolicyView<TrustedUnchecked> {
+	/// Returns a view which does not check its accesses.
+	///
+	/// # Safety
+	///
+	/// Every access through the returned view must be in bounds.
+	#[inline]
+	pub unsafe fn new(view: &DataView) -> &PolicyView<TrustedUnchecked> {
+		PolicyView::cast(view)
+	}
+	/// Returns a mutable view which does not check its accesses.
+	///
+	/// # Safety
+	///
+	/// Every access through the returned view must be in bounds.
+	#[inline]
+	pub unsafe fn new_mut(view: &mut DataView) -> &mut PolicyView<TrustedUnchecked> {
+		PolicyView::cast_mut(view)
+	}
+}
+
+impl<P: BoundsPolicy> PolicyView<P> {
+	#[inline]
+	unsafe fn cast(view: &DataView) -> &PolicyView<P> {
+		&*(view as *const DataView as *const PolicyView<P>)
+	}
+	#[inline]
+	unsafe fn cast_mut(view: &mut DataView) -> &mut PolicyView<P> {
+		&mut *(view as *mut DataView as *mut PolicyView<P>)
+	}
+	/// Reads a (potentially unaligned) value from the view.
+	#[track_caller]
+	#[inline]
+	pub fn read<T: Pod>(&self, offset: usize) -> T {
+		unsafe { P::read(&self.view, offset) }
+	}
+	/// Reads a (potentially unaligned) value from the view into the destination.
+	#[track_caller]
+	#[inline]
+	pub fn read_into<T: ?Sized + Pod>(&self, offset: usize, dest: &mut T) {
+		unsafe { P::read_into(&self.view, offset, dest) }
+	}
+	/// Writes a value into the view.
+	#[track_caller]
+	#[inline]
+	pub fn write<T: ?Sized + Pod>(&mut self, offset: usize, value: &T) {
+		unsafe { P::write(&mut self.view, offset, value) }
+	}
+}
+
+impl<P: BoundsPolicy> ops::Deref for PolicyView<P> {
+	type Target = DataView;
+	#[inline]
+	fn deref(&self) -> &DataView {
+		&self.view
+	}
+}
+impl<P: BoundsPolicy> ops::DerefMut for PolicyView<P> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut DataView {
+		&mut self.view
+	}
+}
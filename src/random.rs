@@ -0,0 +1,53 @@
+use rand::Rng;
+use rand::distr::Distribution;
+use super::*;
+
+/// Overwrites the value with random bytes.
+///
+/// ```
+/// use rand::SeedableRng;
+///
+/// let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+/// let mut buffer = [0u8; 64];
+/// dataview::fill_random(&mut buffer, &mut rng);
+/// assert!(buffer.iter().any(|&byte| byte != 0));
+///
+/// // Works with data views too
+/// let view = dataview::DataView::from_mut(&mut buffer);
+/// dataview::fill_random(&mut view[..8], &mut rng);
+/// ```
+#[inline]
+pub fn fill_random<T: ?Sized + Pod, R: ?Sized + Rng>(value: &mut T, rng: &mut R) {
+	rng.fill_bytes(bytes_mut(value))
+}
+
+/// Distribution sampling Pod values from random bytes.
+///
+/// Every bit pattern is equally likely, eg. floats are not uniformly distributed in any range.
+///
+/// ```
+/// use rand::{RngExt, SeedableRng};
+/// use dataview::StandardPod;
+///
+/// #[derive(dataview::Pod, Copy, Clone, Debug)]
+/// #[repr(C)]
+/// struct Record {
+/// 	id: u32,
+/// 	tag: [u8; 4],
+/// }
+///
+/// let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+/// let records: Vec<Record> = (&mut rng).sample_iter(StandardPod).take(4).collect();
+/// assert_eq!(records.len(), 4);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StandardPod;
+
+impl<T: Pod> Distribution<T> for StandardPod {
+	#[inline]
+	fn sample<R: ?Sized + Rng>(&self, rng: &mut R) -> T {
+		let mut value = zeroed::<T>();
+		fill_random(&mut value, rng);
+		value
+	}
+}
@@ -0,0 +1,138 @@
+/*!
+Serde adapter (de)serializing Pod values as their bytes.
+
+Use it with the `with` attribute:
+
+```
+#[derive(dataview::Pod, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+struct Header {
+	magic: [u8; 4],
+	version: u32,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Message {
+	#[serde(with = "dataview::serde_pod")]
+	header: Header,
+}
+```
+
+The value is written with `serialize_bytes` in native byte order and can be read back from either bytes or a sequence of `u8`.
+Deserialization fails unless exactly `size_of::<T>()` bytes are provided.
+
+[`DataView`] serializes as bytes and can be deserialized by borrowing from the input.
+With the `alloc` feature [`DataViewBuf`](crate::DataViewBuf) can also be deserialized from owned input.
+*/
+
+use core::{fmt, marker::PhantomData, mem};
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use crate::*;
+
+/// Serializes the bytes of the value.
+#[inline]
+pub fn serialize<T: ?Sized + AsBytes, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_bytes(bytes(value))
+}
+
+/// Deserializes a value from its bytes.
+#[inline]
+pub fn deserialize<'de, T: AnyBitPattern, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+	deserializer.deserialize_bytes(PodVisitor(PhantomData))
+}
+
+struct PodVisitor<T>(PhantomData<fn() -> T>);
+
+impl<'de, T: AnyBitPattern> Visitor<'de> for PodVisitor<T> {
+	type Value = T;
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} bytes", mem::size_of::<T>())
+	}
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+		if v.len() != mem::size_of::<T>() {
+			return Err(E::invalid_length(v.len(), &self));
+		}
+		Ok(read_unaligned(v).unwrap())
+	}
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+		let mut value = zeroed::<T>();
+		// Bytes are written one at a time and the result is only read once all bytes are present
+		let dest = unsafe { core::slice::from_raw_parts_mut(&mut value as *mut T as *mut u8, mem::size_of::<T>()) };
+		for (i, byte) in dest.iter_mut().enumerate() {
+			*byte = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+		}
+		if seq.next_element::<de::IgnoredAny>()?.is_some() {
+			return Err(de::Error::invalid_length(dest.len() + 1, &self));
+		}
+		Ok(value)
+	}
+}
+
+impl Serialize for DataView {
+	#[inline]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(self.as_ref())
+	}
+}
+
+struct BorrowedVisitor;
+
+impl<'de> Visitor<'de> for BorrowedVisitor {
+	type Value = &'de DataView;
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("borrowed bytes")
+	}
+	fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<&'de DataView, E> {
+		Ok(DataView::from(v))
+	}
+}
+
+impl<'de: 'a, 'a> de::Deserialize<'de> for &'a DataView {
+	#[inline]
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<&'a DataView, D::Error> {
+		deserializer.deserialize_bytes(BorrowedVisitor)
+	}
+}
+
+#[cfg(feature = "alloc")]
+mod buf {
+	use alloc::vec::Vec;
+	use super::*;
+
+	impl Serialize for DataViewBuf {
+		#[inline]
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.serialize_bytes(self.as_ref())
+		}
+	}
+
+	struct BufVisitor;
+
+	impl<'de> Visitor<'de> for BufVisitor {
+		type Value = DataViewBuf;
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			f.write_str("bytes")
+		}
+		fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<DataViewBuf, E> {
+			Ok(DataViewBuf::from(DataView::from(v)))
+		}
+		fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<DataViewBuf, E> {
+			Ok(DataViewBuf::from(v))
+		}
+		fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<DataViewBuf, A::Error> {
+			let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+			while let Some(byte) = seq.next_element::<u8>()? {
+				bytes.push(byte);
+			}
+			Ok(DataViewBuf::from(bytes))
+		}
+	}
+
+	impl<'de> de::Deserialize<'de> for DataViewBuf {
+		#[inline]
+		fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DataViewBuf, D::Error> {
+			deserializer.deserialize_byte_buf(BufVisitor)
+		}
+	}
+}
@@ -0,0 +1,180 @@
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// Appends values to a byte buffer.
+///
+/// ```
+/// let mut buffer = [0u8; 16];
+/// let mut ser = dataview::Serializer::new(dataview::DataView::from_mut(&mut buffer));
+/// ser.put(&1u8);
+/// ser.align(4);
+/// let offset = ser.put(&2u32);
+/// ser.put_slice(&[3u16, 4]);
+/// assert_eq!(offset, 4);
+///
+/// let message = ser.finish();
+/// assert_eq!(message.len(), 12);
+/// assert_eq!(message.read::<[u16; 2]>(8), [3, 4]);
+/// ```
+///
+/// The *Panicking* methods panic with the message `"invalid offset"` when the buffer is full, see [`DataView`].
+pub struct Serializer<'a> {
+	view: &'a mut DataView,
+	position: usize,
+}
+
+impl<'a> Serializer<'a> {
+	/// Creates a serializer writing from the start of the view.
+	#[inline]
+	pub fn new(view: &'a mut DataView) -> Serializer<'a> {
+		Serializer { view, position: 0 }
+	}
+	/// Returns the number of bytes written.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.position
+	}
+	/// Returns the number of bytes which can still be written.
+	#[inline]
+	pub fn remaining(&self) -> usize {
+		self.view.len() - self.position
+	}
+	/// Returns the bytes written so far.
+	#[inline]
+	pub fn as_data_view(&self) -> &DataView {
+		&self.view[..self.position]
+	}
+	/// Returns the bytes written and ends serialization.
+	#[inline]
+	pub fn finish(self) -> &'a mut DataView {
+		&mut self.view[..self.position]
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Appends a value to the buffer.
+impl<'a> Serializer<'a> {
+	/// Appends a value to the buffer.
+	///
+	/// Returns the offset of the value.
+	#[inline]
+	pub fn try_put<T: ?Sized + AsBytes>(&mut self, value: &T) -> Option<usize> {
+		let offset = self.position;
+		self.view.try_write(offset, value)?;
+		self.position += mem::size_of_val(value);
+		Some(offset)
+	}
+	/// Appends a value to the buffer.
+	///
+	/// Returns the offset of the value.
+	#[track_caller]
+	#[inline]
+	pub fn put<T: ?Sized + AsBytes>(&mut self, value: &T) -> usize {
+		match self.try_put(value) {
+			Some(offset) => offset,
+			None => invalid_offset(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Appends a slice of values to the buffer.
+impl<'a> Serializer<'a> {
+	/// Appends a slice of values to the buffer.
+	///
+	/// Returns the offset of the slice.
+	#[inline]
+	pub fn try_put_slice<T: AsBytes>(&mut self, values: &[T]) -> Option<usize> {
+		let bytes = unsafe { slice::from_raw_parts(values.as_ptr() as *const u8, mem::size_of_val(values)) };
+		self.try_put(bytes)
+	}
+	/// Appends a slice of values to the buffer.
+	///
+	/// Returns the offset of the slice.
+	#[track_caller]
+	#[inline]
+	pub fn put_slice<T: AsBytes>(&mut self, values: &[T]) -> usize {
+		match self.try_put_slice(values) {
+			Some(offset) => offset,
+			None => invalid_offset(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Pads the buffer with zeroes to a multiple of the alignment.
+impl<'a> Serializer<'a> {
+	/// Pads the buffer with zeroes to a multiple of the alignment.
+	///
+	/// The alignment is relative to the start of the buffer and must be a power of two.
+	/// Returns the new position.
+	#[inline]
+	pub fn try_align(&mut self, align: usize) -> Option<usize> {
+		self.try_align_to(align, 0)
+	}
+	/// Pads the buffer with zeroes to a multiple of the alignment.
+	///
+	/// The alignment is relative to the start of the buffer and must be a power of two.
+	/// Returns the new position.
+	#[track_caller]
+	#[inline]
+	pub fn align(&mut self, align: usize) -> usize {
+		match self.try_align(align) {
+			Some(position) => position,
+			None => invalid_offset(),
+		}
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Pads the buffer with a fill byte, eg. to lay out the sections of an executable.
+impl<'a> Serializer<'a> {
+	/// Pads the buffer with the fill byte to a multiple of the alignment.
+	///
+	/// The alignment is relative to the start of the buffer and must be a power of two.
+	/// Returns the new position.
+	#[inline]
+	pub fn try_align_to(&mut self, align: usize, fill: u8) -> Option<usize> {
+		debug_assert!(align.is_power_of_two());
+		let end = self.position.checked_add(align - 1)? & !(align - 1);
+		self.try_pad_to(end, fill)
+	}
+	/// Pads the buffer with the fill byte to a multiple of the alignment.
+	///
+	/// The alignment is relative to the start of the buffer and must be a power of two.
+	/// Returns the new position.
+	#[track_caller]
+	#[inline]
+	pub fn align_to(&mut self, align: usize, fill: u8) -> usize {
+		match self.try_align_to(align, fill) {
+			Some(position) => position,
+			None => invalid_offset(),
+		}
+	}
+	/// Pads the buffer with the fill byte up to the offset.
+	///
+	/// Errors if the offset is before the current position or past the end of the buffer.
+	/// Returns the new position.
+	#[inline]
+	pub fn try_pad_to(&mut self, offset: usize, fill: u8) -> Option<usize> {
+		self.view.index_mut(self.position..offset)?.as_mut().fill(fill);
+		self.position = offset;
+		Some(offset)
+	}
+	/// Pads the buffer with the fill byte up to the offset.
+	///
+	/// Panics if the offset is before the current position or past the end of the buffer.
+	/// Returns the new position.
+	#[track_caller]
+	#[inline]
+	pub fn pad_to(&mut self, offset: usize, fill: u8) -> usize {
+		match self.try_pad_to(offset, fill) {
+			Some(position) => position,
+			None => invalid_offset(),
+		}
+	}
+}
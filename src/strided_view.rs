@@ -0,0 +1,168 @@
+use core::{fmt, iter, ops};
+use core::marker::PhantomData;
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// View over records of type `T` spaced `stride` bytes apart.
+///
+/// The stride may exceed the size of `T`, the bytes in between records are skipped.
+/// This is common for interleaved vertex buffers and pages with per-record headers.
+///
+/// ```
+/// use dataview::{DataView, StridedView};
+///
+/// // Records of a u16 header followed by a u16 value
+/// let records = [0u16, 10, 1, 20, 2, 30];
+/// let view = DataView::from(&records);
+///
+/// let values = StridedView::<u16>::new(view, 2, 4, 3);
+/// assert_eq!(values[1], 20);
+/// assert!(values.iter().copied().eq([10, 20, 30]));
+/// ```
+///
+/// Every record must be aligned for `T`, see [`DataView::get`].
+pub struct StridedView<'a, T> {
+	ptr: *const u8,
+	stride: usize,
+	len: usize,
+	_phantom: PhantomData<&'a [T]>,
+}
+
+unsafe impl<'a, T: Sync> Send for StridedView<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for StridedView<'a, T> {}
+
+impl<'a, T: AnyBitPattern> StridedView<'a, T> {
+	/// Creates a view over `count` records, the first one at `offset` and each next one `stride` bytes further.
+	///
+	/// Returns `None` if any record is out of bounds or misaligned for `T`.
+	#[inline]
+	pub fn try_new(view: &'a DataView, offset: usize, stride: usize, count: usize) -> Option<StridedView<'a, T>> {
+		let size = match count {
+			0 => 0,
+			_ => (count - 1).checked_mul(stride)?.checked_add(mem::size_of::<T>())?,
+		};
+		let bytes = view.index(offset..offset.checked_add(size)?)?.as_ref();
+		let align = mem::align_of::<T>();
+		if !is_aligned(bytes.as_ptr() as *const T) || stride / align * align != stride {
+			return None;
+		}
+		Some(StridedView { ptr: bytes.as_ptr(), stride, len: count, _phantom: PhantomData })
+	}
+	/// Creates a view over `count` records, the first one at `offset` and each next one `stride` bytes further.
+	#[track_caller]
+	#[inline]
+	pub fn new(view: &'a DataView, offset: usize, stride: usize, count: usize) -> StridedView<'a, T> {
+		match StridedView::try_new(view, offset, stride, count) {
+			Some(strided) => strided,
+			None => invalid_offset(),
+		}
+	}
+	/// Returns the number of records.
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+	/// Returns true if there are no records.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+	/// Returns the distance in bytes between records.
+	#[inline]
+	pub const fn stride(&self) -> usize {
+		self.stride
+	}
+	/// Returns a reference to the record at the index.
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<&'a T> {
+		if index >= self.len {
+			return None;
+		}
+		unsafe { Some(self.get_unchecked(index)) }
+	}
+	/// Returns a reference to the record at the index without bounds checking.
+	///
+	/// # Safety
+	///
+	/// The index must be less than [`len`](Self::len).
+	#[inline]
+	pub unsafe fn get_unchecked(&self, index: usize) -> &'a T {
+		&*(self.ptr.add(index * self.stride) as *const T)
+	}
+	/// Returns an iterator over the records.
+	#[inline]
+	pub fn iter(&self) -> StridedIter<'a, T> {
+		StridedIter { view: *self, index: 0 }
+	}
+}
+
+impl<'a, T> Copy for StridedView<'a, T> {}
+impl<'a, T> Clone for StridedView<'a, T> {
+	#[inline]
+	fn clone(&self) -> StridedView<'a, T> {
+		*self
+	}
+}
+
+impl<'a, T: AnyBitPattern> ops::Index<usize> for StridedView<'a, T> {
+	type Output = T;
+	#[track_caller]
+	#[inline]
+	fn index(&self, index: usize) -> &T {
+		match self.get(index) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
+
+impl<'a, T: AnyBitPattern> IntoIterator for StridedView<'a, T> {
+	type Item = &'a T;
+	type IntoIter = StridedIter<'a, T>;
+	#[inline]
+	fn into_iter(self) -> StridedIter<'a, T> {
+		self.iter()
+	}
+}
+
+impl<'a, T: AnyBitPattern + fmt::Debug> fmt::Debug for StridedView<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+/// Iterator over the records of a [`StridedView`].
+#[derive(Clone)]
+pub struct StridedIter<'a, T> {
+	view: StridedView<'a, T>,
+	index: usize,
+}
+
+impl<'a, T: AnyBitPattern> Iterator for StridedIter<'a, T> {
+	type Item = &'a T;
+	#[inline]
+	fn next(&mut self) -> Option<&'a T> {
+		let value = self.view.get(self.index)?;
+		self.index += 1;
+		Some(value)
+	}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.view.len - self.index;
+		(len, Some(len))
+	}
+}
+
+impl<'a, T: AnyBitPattern> DoubleEndedIterator for StridedIter<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<&'a T> {
+		if self.index >= self.view.len {
+			return None;
+		}
+		self.view.len -= 1;
+		unsafe { Some(self.view.get_unchecked(self.view.len)) }
+	}
+}
+
+impl<'a, T: AnyBitPattern> ExactSizeIterator for StridedIter<'a, T> {}
+impl<'a, T: AnyBitPattern> iter::FusedIterator for StridedIter<'a, T> {}
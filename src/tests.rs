@@ -1,3 +1,6 @@
+#![allow(clippy::redundant_pattern_matching, clippy::needless_range_loop)]
+
+
 use super::*;
 
 #[derive(Copy, Clone)]
@@ -5,6 +8,7 @@ use super::*;
 struct Foo([u32; 2]);
 unsafe impl Pod for Foo {}
 
+#[allow(dead_code)]
 #[repr(C, align(4))]
 struct Baz([u32; 2]);
 unsafe impl Pod for Baz {}
@@ -15,6 +19,190 @@ fn test_zeroed() {
 	assert_eq!([0u32; 2], a.0);
 	let b: [f32; 2] = zeroed();
 	assert_eq!([0f32; 2], b);
+
+	const C: Foo = zeroed();
+	static D: [Option<num::NonZeroU32>; 4] = zeroed();
+	assert_eq!([0u32; 2], C.0);
+	assert_eq!([None; 4], D);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_boxed_zeroed() {
+	let a: alloc::boxed::Box<Foo> = boxed_zeroed();
+	assert_eq!([0u32; 2], a.0);
+	let b: alloc::boxed::Box<()> = boxed_zeroed();
+	assert_eq!((), *b);
+	let c: alloc::boxed::Box<[Foo]> = boxed_slice_zeroed(3);
+	assert!(c.iter().all(|foo| foo.0 == [0; 2]));
+	assert_eq!(c.as_ptr() as usize % mem::align_of::<Foo>(), 0);
+	let d: alloc::boxed::Box<[()]> = boxed_slice_zeroed(usize::MAX);
+	assert_eq!(d.len(), usize::MAX);
+	assert_eq!(boxed_slice_zeroed::<u8>(0).len(), 0);
+}
+
+#[test]
+fn test_from_bytes() {
+	let mut a = Foo([1, 2]);
+	assert_eq!(try_from_bytes::<Foo>(bytes(&a)).map(|a| a.0), Some([1, 2]));
+	assert_eq!(try_from_bytes::<u32>(&bytes(&a)[4..]), Some(&2));
+	assert!(try_from_bytes::<u32>(&bytes(&a)[1..5]).is_none());
+	assert!(try_from_bytes::<u32>(&bytes(&a)[..3]).is_none());
+	*try_from_bytes_mut::<u32>(&mut bytes_mut(&mut a)[..4]).unwrap() = 3;
+	assert_eq!(a.0, [3, 2]);
+	assert!(try_from_bytes_mut::<Foo>(&mut bytes_mut(&mut a)[..4]).is_none());
+}
+
+#[test]
+fn test_unaligned() {
+	let mut buf = [0u8; 9];
+	assert_eq!(write_unaligned(&mut buf[1..], &[3u32, 4]), Some(()));
+	assert_eq!(write_unaligned(&mut buf[1..], &5u32), None);
+	assert_eq!(read_unaligned::<[u32; 2]>(&buf[1..]), Some([3, 4]));
+	assert_eq!(read_unaligned::<u32>(&buf[5..]), Some(4));
+	assert_eq!(read_unaligned::<u32>(&buf[6..]), None);
+}
+
+#[test]
+fn test_pod_utils() {
+	let mut a = [1u8, 2, 3];
+	let mut b = [1u8, 2, 4];
+	assert!(pod_eq(&a, &a));
+	assert!(!pod_eq(&a[..], &b[..2]));
+	assert_eq!(pod_cmp(&a, &b), cmp::Ordering::Less);
+	pod_swap(&mut a, &mut b);
+	assert_eq!((a, b), ([1, 2, 4], [1, 2, 3]));
+	pod_swap(&mut a[..1], &mut b[2..]);
+	assert_eq!((a, b), ([3, 2, 4], [1, 2, 1]));
+	copy_bytes(&mut a[..2], &0u16);
+	assert_eq!(a, [0, 0, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_copy_bytes_size_mismatch() {
+	copy_bytes(&mut [0u8; 3], &0u32);
+}
+
+#[test]
+fn test_wrappers() {
+	use core::num::{Saturating, Wrapping};
+	let view = DataView::from(&[0xff_u8; 4]);
+	assert_eq!(view.read::<Wrapping<u16>>(0) + Wrapping(1), Wrapping(0));
+	assert_eq!(view.read::<Saturating<u16>>(2) + Saturating(1), Saturating(u16::MAX));
+	assert_eq!(*view.read::<mem::ManuallyDrop<u32>>(0), u32::MAX);
+}
+
+#[test]
+fn test_option_nonzero() {
+	use core::num::{NonZeroU32, NonZeroU64};
+	let ids = [0u32, 1, 0, 2];
+	let view = DataView::from(&ids);
+	assert_eq!(view.read::<Option<NonZeroU32>>(0), None);
+	assert_eq!(view.read::<Option<NonZeroU32>>(4), NonZeroU32::new(1));
+	let table: [Option<NonZeroU32>; 4] = view.read(0);
+	assert_eq!(table, [None, NonZeroU32::new(1), None, NonZeroU32::new(2)]);
+	let table = view.slice::<Option<NonZeroU32>>(8, 2);
+	assert_eq!(table, &[None, NonZeroU32::new(2)]);
+
+	let ids = [7u64, 0];
+	let table = DataView::from(&ids).slice::<Option<NonZeroU64>>(0, 2);
+	assert_eq!(table, &[NonZeroU64::new(7), None]);
+	assert_eq!(bytes(table), bytes(&ids));
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd() {
+	use core::simd::Simd;
+	let view = DataView::from(&TEST_DATA.1);
+	let lanes: Simd<u8, 4> = view.read(4);
+	assert_eq!(lanes, Simd::from_array([4, 5, 6, 7]));
+	let lanes = view.get::<Simd<u16, 4>>(0);
+	assert_eq!(bytes(lanes), &TEST_DATA.1);
+}
+
+#[cfg(feature = "int2ptr")]
+#[test]
+fn test_fn_ptr() {
+	unsafe extern "C" fn add(a: i32, b: i32) -> i32 { a + b }
+	type Add = Option<unsafe extern "C" fn(i32, i32) -> i32>;
+	let table: [Add; 2] = [Some(add), None];
+	let view = DataView::from(&table);
+	assert!(pod_eq(&view.read::<Add>(0), &table[0]));
+	assert!(view.read::<Add>(mem::size_of::<Add>()).is_none());
+	assert!(zeroed::<Option<unsafe extern "C" fn()>>().is_none());
+}
+
+#[test]
+fn test_tuples() {
+	assert_eq!(zeroed::<(bool, bool)>(), (false, false));
+	assert_eq!(zeroed::<(u16, u16, u16, u16, u16, u16, u16, u16)>(), (0, 0, 0, 0, 0, 0, 0, 0));
+	let _: (Foo, Foo) = zeroed();
+	// The order of the fields in memory is unspecified
+	let view = DataView::from(&TEST_DATA.1);
+	let (a, b) = view.read::<(u8, u8)>(2);
+	assert_eq!(a + b, 5);
+	let pairs = view.slice::<(u16, u16)>(0, 2);
+	assert_eq!(pairs.len(), 2);
+}
+
+#[test]
+fn test_inspect() {
+	#[repr(C)]
+	struct Record {
+		flag: bool,
+		ch: [u8; 3],
+		letter: char,
+	}
+	unsafe impl AsBytes for Record {}
+
+	let record = Record { flag: true, ch: *b"abc", letter: 'x' };
+	let inspect = Inspect::new(&record);
+	assert!(inspect.get().flag);
+	assert_eq!(inspect.len(), 8);
+	assert_eq!(inspect.read::<[u8; 4]>(0), [1, b'a', b'b', b'c']);
+	assert_eq!(inspect.read::<u32>(4), 'x' as u32);
+	assert_eq!(inspect.as_ref(), bytes(&record));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_inspect_debug() {
+	let table: [u8; 18] = core::array::from_fn(|i| i as u8);
+	let dump = alloc::format!("{:?}", Inspect::new(&table));
+	assert_eq!(dump, "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f\n00000010: 10 11");
+	assert_eq!(alloc::format!("{:?}", Inspect::new(&[0u8; 0])), "");
+}
+
+#[test]
+fn test_capabilities() {
+	#[derive(Copy, Clone, Debug, PartialEq)]
+	#[repr(C)]
+	struct Padded {
+		byte: u8,
+		word: u32,
+	}
+	unsafe impl Zeroable for Padded {}
+	unsafe impl AnyBitPattern for Padded {}
+
+	assert_eq!(zeroed::<Padded>(), Padded { byte: 0, word: 0 });
+	assert!(!zeroed::<bool>());
+	assert!(zeroed::<*const u8>().is_null());
+
+	let data = [0x11u32, 0x22];
+	let view = DataView::from(&data);
+	assert_eq!(view.read::<Padded>(0).word, 0x22);
+	assert_eq!(view.get::<Padded>(0).word, 0x22);
+
+	assert_eq!(bytes(&true), &[1]);
+	assert_eq!(DataView::from("abc").as_ref(), b"abc");
+	let mut buf = [0u8; 5];
+	let view = DataView::from_mut(&mut buf);
+	view.write(0, &true);
+	view.write(1, &'A');
+	assert_eq!(view.read::<u8>(0), 1);
+	assert_eq!(view.read::<u32>(1), 'A' as u32);
 }
 
 //------------------------------------------------
@@ -43,6 +231,44 @@ fn test_read() {
 		assert_eq!(value, unsafe { view.read_unchecked(i) });
 	}
 	assert!(matches!(view.try_read::<u8>(view.len()), None));
+	assert!(matches!(view.try_read::<u16>(usize::MAX), None));
+}
+
+#[test]
+fn test_const_read() {
+	const TABLE: &DataView = DataView::from_bytes(&[1, 0, 2, 0, 3, 0, 4]);
+	const fn sum(view: &DataView) -> u16 {
+		let mut sum = 0;
+		let mut offset = 0;
+		while let Some(value) = view.try_read::<u16>(offset) {
+			sum += u16::from_le(value);
+			offset += 2;
+		}
+		sum
+	}
+	const SUM: u16 = sum(TABLE);
+	assert_eq!(SUM, 6);
+	const TAIL: Option<[u8; 3]> = TABLE.try_read(4);
+	assert_eq!(TAIL, Some([3, 0, 4]));
+}
+
+#[test]
+fn test_write() {
+	let mut data = TEST_DATA;
+	let view = DataView::from_mut(&mut data.1);
+	for i in 0..view.len() {
+		let value = !(i as u8);
+		assert_eq!(Some(()), view.try_write(i, &value));
+		assert_eq!(value, view.read(i));
+	}
+	view.write(1, &0x1234_u16);
+	assert_eq!(0x1234_u16, view.read(1));
+	unsafe { view.write_unchecked(3, &0x56789abc_u32); }
+	assert_eq!(0x56789abc_u32, view.read(3));
+	view.write(0, &[1u8; 8]);
+	assert_eq!(&[1u8; 8], view.as_ref());
+	assert!(matches!(view.try_write(7, &0_u16), None));
+	assert!(matches!(view.try_write(usize::MAX, &0_u16), None));
 }
 
 #[test]
@@ -67,6 +293,21 @@ fn test_read_into() {
 	assert!(matches!(view.try_read_into::<u8>(view.len(), &mut dest), None));
 }
 
+#[test]
+fn test_read_uninit() {
+	let bytes = &TEST_DATA.1;
+	let view = DataView::from(bytes);
+	let mut dest = mem::MaybeUninit::<[u8; 3]>::uninit();
+	assert_eq!(Some(&mut [2, 3, 4]), view.try_read_uninit(2, &mut dest));
+	assert_eq!(&mut [5, 6, 7], view.read_uninit(5, &mut dest));
+	assert_eq!(&mut [0, 1, 2], unsafe { view.read_uninit_unchecked(0, &mut dest) });
+	assert!(matches!(view.try_read_uninit(6, &mut dest), None));
+	let mut dest = [mem::MaybeUninit::<u8>::uninit(); 4];
+	assert_eq!(Some(&mut [4, 5, 6, 7][..]), view.try_read_uninit_slice(4, &mut dest));
+	assert_eq!(&mut [1, 2, 3, 4], view.read_uninit_slice(1, &mut dest));
+	assert!(matches!(view.try_read_uninit_slice(5, &mut dest), None));
+}
+
 #[test]
 fn test_get() {
 	let bytes = &TEST_DATA.1;
@@ -84,6 +325,45 @@ fn test_get() {
 	assert!(matches!(view.try_get::<u8>(view.len()), None));
 }
 
+#[test]
+fn test_unalign() {
+	let mut data = TEST_DATA;
+	let view = DataView::from_mut(&mut data.1);
+	assert_eq!(mem::align_of::<Unalign<u64>>(), 1);
+	assert_eq!(view.get::<Unalign<u16>>(1).get(), u16::from_ne_bytes([1, 2]));
+	view.get_mut::<Unalign<u32>>(3).set(0xdeadbeef);
+	assert_eq!(view.read::<u32>(3), 0xdeadbeef);
+	assert_eq!(view.read::<Unalign<u32>>(3), Unalign::new(0xdeadbeef));
+	assert_eq!(Unalign::new(5u8).into_inner(), 5);
+}
+
+#[test]
+fn test_endian() {
+	let mut bytes = [0u8, 1, 2, 3, 4, 5, 6, 7, 8];
+	let view = DataView::from_mut(&mut bytes);
+	assert_eq!(mem::align_of::<Le<u64>>(), 1);
+	assert_eq!(view.get::<Le<u32>>(1).get(), 0x04030201);
+	assert_eq!(view.get::<Be<u32>>(1).get(), 0x01020304);
+	assert_eq!(view.read::<Be<[u16; 2]>>(5).get(), [0x0506, 0x0708]);
+	view.get_mut::<Be<f32>>(1).set(1.0);
+	assert_eq!(bytes[1..5], [0x3f, 0x80, 0, 0]);
+	assert_eq!(Le::new(-2i16), Le::from(-2i16));
+	assert_eq!(Be::<u64>::default().get(), 0);
+}
+
+#[test]
+fn test_aligned() {
+	assert_eq!(mem::align_of::<Aligned<A4096, u8>>(), 4096);
+	assert_eq!(mem::size_of::<Aligned<A16, [u8; 32]>>(), 32);
+	let mut buffer = Aligned::<A64, [u8; 3]>::new([1, 2, 3]);
+	assert_eq!(buffer.as_data_view().as_ref().as_ptr() as usize % 64, 0);
+	assert_eq!(buffer.as_data_view().len(), 3);
+	buffer.as_data_view_mut().write(0, &[4u8, 5]);
+	assert_eq!(buffer.into_inner(), [4, 5, 3]);
+	let zeroed: Aligned<A8, [u16; 4]> = zeroed();
+	assert_eq!(zeroed.value, [0; 4]);
+}
+
 #[test]
 fn test_get_mut() {
 	let mut data = TEST_DATA;
@@ -103,6 +383,23 @@ fn test_get_mut() {
 	assert!(matches!(view.try_get_mut::<u8>(view.len()), None));
 }
 
+#[test]
+fn test_get_many_mut() {
+	let mut data = TEST_DATA;
+	let view = DataView::from_mut(&mut data.1);
+	let [a, b] = view.get_many_mut::<u16, 2>([0, 6]);
+	*a = 0x1111;
+	*b = 0x2222;
+	assert_eq!(0x1111_u16, view.read(0));
+	assert_eq!(0x2222_u16, view.read(6));
+	assert!(view.try_get_many_mut::<u16, 3>([0, 2, 4]).is_some());
+	assert!(matches!(view.try_get_many_mut::<u32, 2>([0, 2]), None));
+	assert!(matches!(view.try_get_many_mut::<u16, 2>([4, 4]), None));
+	assert!(matches!(view.try_get_many_mut::<u16, 2>([0, 3]), None));
+	assert!(matches!(view.try_get_many_mut::<u16, 2>([0, 8]), None));
+	assert!(view.try_get_many_mut::<(), 2>([3, 3]).is_some());
+}
+
 #[test]
 fn test_slice() {
 	let bytes = &TEST_DATA.1;
@@ -142,3 +439,698 @@ fn test_slice_mut() {
 	assert_eq!(view.try_slice_mut::<u8>(check.len(), 0), Some(&mut [] as &mut [u8]));
 	assert!(matches!(view.try_slice_mut::<u8>(view.len(), 1), None));
 }
+
+#[test]
+fn test_read_volatile() {
+	let bytes = &TEST_DATA.1;
+	let view = DataView::from(bytes);
+	for i in 0..bytes.len() {
+		let value = i as u8;
+		assert_eq!(Some(value), view.try_read_volatile(i));
+		assert_eq!(value, view.read_volatile(i));
+		assert_eq!(value, unsafe { view.read_volatile_unchecked(i) });
+		if i % 2 == 1 {
+			assert!(matches!(view.try_read_volatile::<u16>(i), None));
+		}
+	}
+	assert_eq!(Some(u32::from_ne_bytes([4, 5, 6, 7])), view.try_read_volatile(4));
+	assert!(matches!(view.try_read_volatile::<u8>(view.len()), None));
+}
+
+#[test]
+fn test_write_volatile() {
+	let mut data = TEST_DATA;
+	let view = DataView::from_mut(&mut data.1);
+	assert_eq!(Some(()), view.try_write_volatile(4, &0xdeadbeef_u32));
+	assert_eq!(0xdeadbeef_u32, view.read(4));
+	view.write_volatile(2, &0x1234_u16);
+	assert_eq!(0x1234_u16, view.read(2));
+	unsafe { view.write_volatile_unchecked(1, &0xff_u8); }
+	assert_eq!(0xff_u8, view.read(1));
+	assert!(matches!(view.try_write_volatile(1, &0_u16), None));
+	assert!(matches!(view.try_write_volatile(8, &0_u8), None));
+}
+
+#[test]
+fn test_write_stream() {
+	let mut src = [0u8; 100];
+	for i in 0..src.len() {
+		src[i] = i as u8;
+	}
+	let mut buf = [0u64; 16];
+	let view = DataView::from_mut(&mut buf);
+	for offset in 0..=28 {
+		view.as_mut().fill(0xff);
+		assert_eq!(Some(()), view.try_write_stream(offset, &src[..]));
+		assert_eq!(&view.as_ref()[offset..offset + src.len()], &src[..]);
+		assert!(view.as_ref()[..offset].iter().all(|&byte| byte == 0xff));
+		assert!(view.as_ref()[offset + src.len()..].iter().all(|&byte| byte == 0xff));
+	}
+	view.write_stream(0, &1_u32);
+	assert_eq!(1_u32, view.read(0));
+	unsafe { view.write_stream_unchecked(4, &2_u32); }
+	assert_eq!(2_u32, view.read(4));
+	assert!(matches!(view.try_write_stream(view.len() - 3, &0_u32), None));
+}
+
+#[test]
+fn test_atomic() {
+	use core::sync::atomic::Ordering::*;
+	let mut data = TEST_DATA;
+	let view = DataView::from_mut(&mut data.1);
+	assert_eq!(Some(u32::from_ne_bytes([4, 5, 6, 7])), view.try_atomic_load(4, Relaxed));
+	assert!(matches!(view.try_atomic_load::<u32>(2, Relaxed), None));
+	assert!(matches!(view.try_atomic_load::<u32>(8, Relaxed), None));
+	view.atomic_store(4, 10_u32, Release);
+	assert_eq!(10_u32, view.atomic_load(4, Acquire));
+	assert_eq!(Ok(10_u32), view.atomic_compare_exchange(4, 10, 20, AcqRel, Acquire));
+	assert_eq!(Err(20_u32), view.atomic_compare_exchange(4, 10, 30, AcqRel, Acquire));
+	assert!(matches!(view.try_atomic_store(1, 0_u16, Relaxed), None));
+	assert!(matches!(view.try_atomic_compare_exchange(6, 0_u32, 1, Relaxed, Relaxed), None));
+	assert!(view.try_get_atomic::<u32>(2).is_none());
+	assert!(view.try_get_atomic::<u32>(8).is_none());
+	assert_eq!(view.get_atomic::<u32>(4).load(Relaxed), 20);
+}
+
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+#[test]
+fn test_atomic_threads() {
+	use core::sync::atomic::Ordering::*;
+	let mut buffer = [0u64; 2];
+	let (flag, counter) = buffer.split_at_mut(1);
+	let flag = DataView::from_mut(flag).get_atomic::<u32>(0);
+	let counter = DataView::from_mut(counter).get_atomic::<u64>(0);
+	std::thread::scope(|s| {
+		for _ in 0..4 {
+			s.spawn(|| {
+				for _ in 0..1000 {
+					counter.fetch_add(1, Relaxed);
+				}
+				let _ = flag.compare_exchange(0, 1, AcqRel, Acquire);
+			});
+		}
+	});
+	assert_eq!(flag.load(Acquire), 1);
+	assert_eq!(buffer[1], 4000);
+}
+
+#[test]
+fn test_zip_records() {
+	let a = [1u16, 2, 3, 4, 5];
+	let b = [10u32, 20, 30, 40];
+	let a = DataView::from(&a);
+	let b = DataView::from(&b);
+	let mut iter = a.zip_records::<u16, u32>(2, b.index(4..).unwrap(), 4);
+	assert_eq!(iter.len(), 3);
+	assert_eq!(iter.next(), Some((1, 20)));
+	assert_eq!(iter.next(), Some((2, 30)));
+	assert_eq!(iter.next(), Some((3, 40)));
+	assert_eq!(iter.next(), None);
+	let mut iter = a.zip_records::<u16, [u8; 4]>(2, b, 1);
+	assert_eq!(iter.len(), 5);
+	assert_eq!(iter.nth(4), Some((5, 20u32.to_ne_bytes())));
+	assert_eq!(a.zip_records::<[u8; 16], u8>(1, b, 1).count(), 0);
+}
+
+#[test]
+fn test_fixed_view() {
+	let mut view = FixedView::new(TEST_DATA.1);
+	assert_eq!(view.read_at::<u8, 7>(), 7);
+	assert_eq!(view.read_at::<[u8; 2], 3>(), [3, 4]);
+	view.write_at::<u32, 4>(&0xdeadbeef);
+	assert_eq!(view.read_at::<u32, 4>(), 0xdeadbeef);
+	assert_eq!(view.read::<u32>(4), 0xdeadbeef);
+	view.write_at::<[u8; 0], 8>(&[]);
+	assert_eq!(&view.into_inner()[..4], &TEST_DATA.1[..4]);
+	assert_eq!(FixedView::from_ref(&TEST_DATA.1).len(), 8);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_data_view_buf() {
+	let mut buf = DataViewBuf::with_capacity(16);
+	assert!(buf.capacity() >= 16);
+	assert_eq!(buf.push(&1u32), 0);
+	assert_eq!(buf.push(&[2u16, 3]), 4);
+	assert_eq!(buf.extend_from_view(DataView::from(&4u8)), 8);
+	assert_eq!(buf.len(), 9);
+	assert_eq!(buf.read::<[u16; 2]>(4), [2, 3]);
+	buf.write(0, &5u32);
+	assert_eq!(buf.read::<u32>(0), 5);
+	buf.resize(12);
+	assert_eq!(buf.as_ref()[8..], [4, 0, 0, 0]);
+	let tail = buf.split_off(8);
+	assert_eq!(tail.as_ref(), [4, 0, 0, 0]);
+	assert_eq!(buf.len(), 8);
+	assert!(buf.split_off(8).is_empty());
+	buf.truncate(4);
+	let bytes: alloc::vec::Vec<u8> = buf.clone().into();
+	assert_eq!(DataViewBuf::from(bytes), buf);
+	assert_eq!(DataViewBuf::from(DataView::from(&5u32)), buf);
+	buf.clear();
+	assert!(buf.is_empty());
+
+	buf.push(&1u8);
+	assert_eq!(buf.align_to(4, 0xcc), 4);
+	assert_eq!(buf.align_to(4, 0xcc), 4);
+	assert_eq!(buf.pad_to(6, 0x90), 6);
+	assert_eq!(buf.try_pad_to(5, 0x90), None);
+	assert_eq!(buf.as_ref(), [1, 0xcc, 0xcc, 0xcc, 0x90, 0x90]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn test_data_view_buf_split_off() {
+	let mut buf = DataViewBuf::zeroed(4);
+	let _ = buf.split_off(5);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_to_vec() {
+	let view = DataView::from(&TEST_DATA.1);
+	assert_eq!(view.to_vec(), TEST_DATA.1);
+	assert_eq!(view[2..5].to_vec(), [2, 3, 4]);
+	let boxed = view[4..].to_boxed_slice();
+	assert_eq!(boxed.read::<[u8; 4]>(0), [4, 5, 6, 7]);
+	assert!(DataView::from(&[0u8; 0]).to_boxed_slice().is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_shared_view() {
+	use alloc::rc::Rc;
+	use alloc::sync::Arc;
+	use alloc::vec;
+
+	let arc = DataView::from_arc(vec![1, 2, 3, 4].into());
+	let clone = Arc::clone(&arc);
+	assert_eq!(clone.read::<[u8; 4]>(0), [1, 2, 3, 4]);
+	drop(clone);
+	let bytes = DataView::into_arc_bytes(arc);
+	assert_eq!(bytes[..], [1, 2, 3, 4]);
+	assert_eq!(Arc::strong_count(&bytes), 1);
+
+	let rc = DataView::from_rc(Rc::from(&[5u8, 6][..]));
+	let clone = Rc::clone(&rc);
+	assert_eq!(clone.read::<u8>(1), 6);
+	drop(clone);
+	assert_eq!(DataView::into_rc_bytes(rc)[..], [5, 6]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_collect_vec() {
+	let bytes = &TEST_DATA.1;
+	let view = DataView::from(bytes);
+	let values = view.collect_vec::<u16>(1, 3);
+	assert_eq!(values, [view.read::<u16>(1), view.read::<u16>(3), view.read::<u16>(5)]);
+	assert_eq!(values.as_ptr() as usize % mem::align_of::<u16>(), 0);
+	assert!(view.try_collect_vec::<u16>(view.len() - 3, 2).is_none());
+	assert_eq!(view.collect_vec::<()>(view.len(), 5).len(), 5);
+	assert_eq!(collect_vec::<u16>(&bytes[1..7]).unwrap().len(), 3);
+	assert!(collect_vec::<u16>(&bytes[1..]).is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_boxed_view() {
+	use alloc::borrow::{Cow, ToOwned};
+	use alloc::boxed::Box;
+
+	let bytes: Box<[u8]> = Box::new([1, 2, 3, 4]);
+	let view: Box<DataView> = bytes.into();
+	let mut copy = view.clone();
+	copy.write(0, &0u8);
+	assert_eq!(view.read::<u8>(0), 1);
+	assert_eq!(Box::<[u8]>::from(copy)[..], [0, 2, 3, 4]);
+
+	let owned = DataView::to_owned(&view);
+	assert_eq!(AsRef::<[u8]>::as_ref(&owned), AsRef::<[u8]>::as_ref(&*view));
+	let cow: Cow<DataView> = Cow::Borrowed(&view);
+	assert_eq!(cow.into_owned(), owned);
+	let boxed: Box<DataView> = owned.into();
+	assert_eq!(boxed.len(), 4);
+}
+
+#[test]
+fn test_serializer() {
+	let mut buffer = [0xffu8; 12];
+	let mut ser = Serializer::new(DataView::from_mut(&mut buffer));
+	assert_eq!(ser.put(&1u8), 0);
+	assert_eq!(ser.align(4), 4);
+	assert_eq!(ser.align(4), 4);
+	assert_eq!(ser.put_slice(&[2u16, 3]), 4);
+	assert_eq!(ser.as_data_view().len(), 8);
+	assert_eq!(ser.try_put(&4u64), None);
+	assert_eq!(ser.try_align(16), None);
+	assert_eq!(ser.position(), 8);
+	assert_eq!(ser.remaining(), 4);
+	assert_eq!(ser.put(&5u32), 8);
+	assert_eq!(ser.try_put(&0u8), None);
+	assert_eq!(ser.finish().len(), 12);
+	assert_eq!(buffer[..4], [1, 0, 0, 0]);
+
+	let mut buffer = [0u8; 12];
+	let mut ser = Serializer::new(DataView::from_mut(&mut buffer));
+	ser.put(&1u8);
+	assert_eq!(ser.align_to(4, 0xcc), 4);
+	assert_eq!(ser.pad_to(6, 0x90), 6);
+	assert_eq!(ser.try_pad_to(5, 0x90), None);
+	assert_eq!(ser.pad_to(6, 0x90), 6);
+	assert_eq!(ser.try_pad_to(13, 0x90), None);
+	assert_eq!(ser.try_align_to(16, 0xcc), None);
+	assert_eq!(ser.position(), 6);
+	assert_eq!(buffer[..6], [1, 0xcc, 0xcc, 0xcc, 0x90, 0x90]);
+}
+
+#[test]
+fn test_arena() {
+	let mut buffer = Aligned::<A8, [u8; 32]>::new([0xff; 32]);
+	let mut arena = Arena::new(DataView::from_mut(&mut *buffer));
+	{
+		let a = arena.alloc(1u8);
+		let b = arena.alloc(2u64);
+		let c = arena.alloc_slice_zeroed::<u16>(3);
+		assert_eq!((*a, *b), (1, 2));
+		assert_eq!(c, &[0, 0, 0]);
+		assert_eq!(b as *mut u64 as usize % 8, 0);
+		assert_eq!(arena.used(), 22);
+		assert_eq!(arena.remaining(), 10);
+		assert!(arena.try_alloc([0u64; 2]).is_none());
+		assert!(arena.try_alloc_slice_copy(&[7u16; 5]).is_some());
+		assert!(arena.try_alloc(0u8).is_none());
+		assert!(arena.try_alloc_slice_zeroed::<u8>(usize::MAX).is_none());
+	}
+	arena.reset();
+	assert_eq!(arena.used(), 0);
+	assert_eq!(arena.try_alloc_slice_copy(&[1u32; 8]).map(|s| s.len()), Some(8));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_cow_view() {
+	let bytes = [1u8, 2, 3, 4];
+	let mut view = CowView::from(DataView::from(&bytes));
+	assert!(view.is_borrowed());
+	assert_eq!(view.read::<u8>(1), 2);
+	let copy = view.clone();
+	view.write(1, &5u8);
+	assert!(view.is_owned());
+	assert!(copy.is_borrowed());
+	assert_eq!(view.as_ref(), &[1, 5, 3, 4]);
+	view.to_mut().push(&6u8);
+	assert_eq!(view.into_owned().into_vec(), [1, 5, 3, 4, 6]);
+	assert_eq!(bytes, [1, 2, 3, 4]);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck() {
+	use bytemuck::TransparentWrapper;
+
+	let mut values = [1u32, 2];
+	let view = DataView::from_bytemuck_mut(&mut values);
+	view.write(4, &Bytemuck(3u32));
+	assert_eq!(view.read::<Bytemuck<u32>>(0), Bytemuck(1));
+	assert_eq!(values, [1, 3]);
+
+	let wrapped: &[Bytemuck<u32>] = Bytemuck::wrap_slice(&values);
+	assert_eq!(DataView::from(wrapped).len(), 8);
+	assert_eq!(bytemuck::cast::<_, Bytemuck<[u16; 2]>>(Bytemuck(0u32)).0, [0, 0]);
+	assert_eq!(DataView::from_bytemuck(&7u8).read::<u8>(0), 7);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+fn test_zerocopy() {
+	let mut values = [1u16, 2, 3];
+	let view = DataView::from_zerocopy_mut(&mut values);
+	view.write(2, &Zerocopy(5u16));
+	assert_eq!(view.read::<Zerocopy<u16>>(4), Zerocopy(3));
+	assert_eq!(view.try_read_zerocopy::<u32>(1), Some(u32::from_ne_bytes([0, 5, 0, 3])));
+	assert_eq!(view.try_read_zerocopy::<u32>(3), None);
+	assert_eq!(view.try_read_zerocopy::<u8>(7), None);
+	assert_eq!(values, [1, 5, 3]);
+	assert_eq!(DataView::from_zerocopy(&values[..]).len(), 6);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+	use arbitrary::{Arbitrary, Unstructured};
+
+	let mut u = Unstructured::new(&[1, 0, 0, 0, 2, 3, 4]);
+	let value = ArbitraryPod::<u32>::arbitrary(&mut u).unwrap();
+	assert_eq!(*value, u32::from_ne_bytes([1, 0, 0, 0]));
+	let view = <&DataView>::arbitrary_take_rest(u).unwrap();
+	assert_eq!(view.as_ref(), &[2, 3, 4]);
+
+	let mut u = Unstructured::new(&[5]);
+	assert_eq!(ArbitraryPod::<[u8; 3]>::arbitrary(&mut u).unwrap().0, [5, 0, 0]);
+	assert_eq!(ArbitraryPod::<[u8; 3]>::size_hint(0), (3, Some(3)));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless() {
+	let mut buf = heapless::Vec::<u8, 6>::new();
+	assert_eq!(buf.push_pod(&1u16), 0);
+	assert_eq!(buf.push_pod("ab"), 2);
+	assert_eq!(buf.try_push_pod(&[0u8; 3]), None);
+	assert_eq!(buf.len(), 4);
+	buf.as_data_view_mut().write(0, &2u16);
+	assert_eq!(buf.as_data_view().read::<u16>(0), 2);
+	assert_eq!(buf.try_push_pod(&[3u8, 4]), Some(4));
+	assert_eq!(buf[2..], *b"ab\x03\x04");
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_random() {
+	use rand::{RngExt, SeedableRng};
+	use rand::rngs::SmallRng;
+
+	let mut a = [0u32; 4];
+	fill_random(&mut a, &mut SmallRng::seed_from_u64(1));
+	let b: [u32; 4] = SmallRng::seed_from_u64(1).sample(StandardPod);
+	assert_eq!(a, b);
+	assert_ne!(a, [0; 4]);
+
+	let mut bytes = [0u8; 8];
+	fill_random(&mut DataView::from_mut(&mut bytes)[2..4], &mut SmallRng::seed_from_u64(2));
+	assert_eq!(bytes[..2], [0, 0]);
+	assert_eq!(bytes[4..], [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_hash_into() {
+	struct Bytes([u8; 8], usize);
+	impl core::hash::Hasher for Bytes {
+		fn finish(&self) -> u64 { u64::from_ne_bytes(self.0) }
+		fn write(&mut self, bytes: &[u8]) {
+			self.0[self.1..self.1 + bytes.len()].copy_from_slice(bytes);
+			self.1 += bytes.len();
+		}
+	}
+
+	let mut state = Bytes([0; 8], 0);
+	hash_bytes(&[1u8, 2], &mut state);
+	DataView::from(&[3u8, 4, 5])[1..].hash_into(&mut state);
+	hash_bytes("xy", &mut state);
+	assert_eq!(state.0, [1, 2, 4, 5, b'x', b'y', 0, 0]);
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn test_mint() {
+	let view = DataView::from(&[1.0f32, 2.0, 3.0, 4.0]);
+	let v: mint::Vector3<f32> = view.read(4);
+	assert_eq!(v, mint::Vector3 { x: 2.0, y: 3.0, z: 4.0 });
+	let m: &mint::ColumnMatrix2<f32> = view.get(0);
+	assert_eq!(m.y, mint::Vector2 { x: 3.0, y: 4.0 });
+	assert_eq!(mem::size_of::<mint::RowMatrix4x3<f64>>(), 96);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam() {
+	let view = DataView::from(&[1.0f32, 2.0, 3.0, 4.0]);
+	assert_eq!(view.read::<glam::Vec3>(4), glam::Vec3::new(2.0, 3.0, 4.0));
+	assert_eq!(view.read::<glam::Vec3A>(0), glam::Vec3A::new(1.0, 2.0, 3.0));
+	assert_eq!(MemoryView::read::<glam::Vec3A>(view, 0), glam::Vec3A::new(1.0, 2.0, 3.0));
+	assert_eq!(view.read::<glam::Mat2>(0).y_axis, glam::Vec2::new(3.0, 4.0));
+	let mut ivec = glam::IVec2::ZERO;
+	DataView::from_mut(&mut ivec).write(4, &7i32);
+	assert_eq!(ivec.y, 7);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io() {
+	use std::io;
+
+	let mut out = alloc::vec::Vec::new();
+	out.write_pod(&[1u16, 2]).unwrap();
+	out.write_pod(&3u8).unwrap();
+	let mut reader = &out[..];
+	assert_eq!(reader.read_pod::<[u16; 2]>().unwrap(), [1, 2]);
+	let mut byte = 0u8;
+	reader.read_pod_into(&mut byte).unwrap();
+	assert_eq!(byte, 3);
+	assert_eq!(reader.read_pod::<u8>().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_file() {
+	let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/odd.bin");
+	let contents = std::fs::read(path).unwrap();
+	assert_eq!(contents.len(), 9);
+
+	let buf = DataViewBuf::read_file(path).unwrap();
+	assert_eq!(buf.as_ref(), &contents[..]);
+
+	let table = DataViewBuf::read_file_aligned::<u32>(path).unwrap();
+	assert_eq!(table.len(), 3);
+	assert_eq!(bytes(&*table)[..9], contents[..]);
+	assert_eq!(bytes(&*table)[9..], [0, 0, 0]);
+
+	let missing = DataViewBuf::read_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/missing.bin"));
+	assert_eq!(missing.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+fn test_strided_view() {
+	let records = [[1u32, 0, 0], [2, 0, 0], [3, 0, 0], [4, 0, 0]];
+	let view = DataView::from(&records);
+	let strided = StridedView::<u32>::new(view, 0, 12, 4);
+	assert_eq!(strided.len(), 4);
+	assert_eq!(strided.get(3), Some(&4));
+	assert_eq!(strided.get(4), None);
+	assert!(strided.iter().rev().copied().eq([4, 3, 2, 1]));
+	assert_eq!(strided.iter().len(), 4);
+
+	assert!(StridedView::<u32>::try_new(view, 4, 12, 4).is_some());
+	assert!(StridedView::<u32>::try_new(view, 12, 12, 4).is_none());
+	assert!(StridedView::<u32>::try_new(view, 0, 6, 2).is_none());
+	assert!(StridedView::<u32>::try_new(view, 2, 12, 1).is_none());
+	assert!(StridedView::<u32>::try_new(view, 48, 12, 0).unwrap().is_empty());
+	assert!(StridedView::<u32>::try_new(view, 0, usize::MAX, 2).is_none());
+}
+
+#[test]
+fn test_columns() {
+	let records = [1u8, 10, 20, 2, 30, 40, 3];
+	let view = DataView::from(&records);
+	assert!(view.column::<u8>(0, 3).eq([1, 2, 3]));
+	assert!(view.column::<u8>(2, 3).eq([20, 40]));
+	assert_eq!(view.column::<u16>(1, 3).len(), 2);
+	assert_eq!(view.column::<u8>(8, 3).len(), 0);
+
+	let [a, b] = view.split_columns(3, [0..1, 1..3]);
+	assert_eq!(a.len(), 3);
+	assert!(b.map(|v| v.as_ref()).eq([&[10, 20], &[30, 40]]));
+	assert_eq!(view.column_bytes(3..3, 3).len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_columns_span_outside_record() {
+	let _ = DataView::from(&[0u8; 8]).column_bytes(2..5, 4);
+}
+
+#[test]
+fn test_memory_view() {
+	fn roundtrip<M: ?Sized + MemoryViewMut>(memory: &mut M) -> Option<u32> {
+		memory.try_write(1, &0x01020304_u32)?;
+		memory.try_read::<u32>(1)
+	}
+
+	let mut bytes = [0u8; 6];
+	assert_eq!(roundtrip(&mut bytes[..]), Some(0x01020304));
+	assert_eq!(roundtrip(DataView::from_mut(&mut bytes)), Some(0x01020304));
+	assert_eq!(roundtrip(&mut FixedView::<4>::zeroed()), None);
+	assert_eq!(MemoryView::len(&&bytes[..]), 6);
+	assert_eq!(MemoryView::try_slice::<u8>(&bytes[..], 4, 2), Some(&bytes[4..]));
+	assert_eq!(MemoryView::try_read::<u8>(&bytes[..], usize::MAX), None);
+
+	#[derive(Copy, Clone, Debug, PartialEq)]
+	#[repr(C)]
+	struct Padded {
+		byte: u8,
+		word: u32,
+	}
+	unsafe impl Zeroable for Padded {}
+	unsafe impl AnyBitPattern for Padded {}
+
+	let src = [1u8, 0xff, 0xff, 0xff, 2, 0, 0, 0];
+	assert_eq!(MemoryView::read::<Padded>(&src[..], 0), Padded { byte: 1, word: u32::from_ne_bytes([2, 0, 0, 0]) });
+}
+
+#[test]
+fn test_checksum() {
+	use crate::checksum::*;
+
+	let data = [0xa5u8; 10000];
+	let view = DataView::from(&data);
+	let mut adler = Adler32::new();
+	let mut fletcher = Fletcher16::new();
+	for chunk in data.chunks(333) {
+		adler.update(chunk);
+		fletcher.update(chunk);
+	}
+	assert_eq!(adler.finish(), view.adler32(..));
+	assert_eq!(fletcher.finish(), view.fletcher16(..));
+	assert_eq!(view.crc32(..0), 0);
+	assert_eq!(view.crc16(..0), 0xffff);
+	assert_eq!(Crc32::checksum(&[0u32; 1]), 0x2144df1c);
+	assert_eq!(view.try_adler32(..10001), None);
+	assert_eq!(Fletcher16::checksum(b"abcdefgh"), 0x0627);
+	assert_eq!(Adler32::checksum(b""), 1);
+	assert_eq!(Fletcher16::checksum(&[0xffu8; 100000]), 0);
+	assert_eq!(Adler32::checksum(&[0xffu8; 100000]), 0x149a_302c);
+}
+
+#[test]
+fn test_hex() {
+	let mut bytes = [0u8; 4];
+	let view = DataView::from_mut(&mut bytes);
+	assert_eq!(view.write_hex(0, "  0aF0\n1 2 "), 3);
+	assert_eq!(view.try_write_hex(0, "abc"), None);
+	assert_eq!(view.try_write_hex(4, ""), Some(0));
+	assert_eq!(view.try_write_hex(usize::MAX, "00"), None);
+	let mut buf = [0u8; 8];
+	assert_eq!(view.encode_hex(..3, &mut buf), "0af012");
+	assert_eq!(view.try_encode_hex(5.., &mut buf), None);
+	assert_eq!(bytes, [0x0a, 0xf0, 0x12, 0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_to_hex_string() {
+	let view = DataView::from(&[0u8, 0x7f, 0x80, 0xff]);
+	assert_eq!(view.to_hex_string(), "007f80ff");
+	assert_eq!(view[..0].to_hex_string(), "");
+}
+
+#[test]
+fn test_valid_offset() {
+	let mut data = TEST_DATA;
+	let view = DataView::from_mut(&mut data.1);
+	let offset = ValidOffset::<u16>::new(6);
+	assert_eq!(offset.get(), 6);
+	assert_eq!(Some(&u16::from_ne_bytes([6, 7])), view.try_get_at(offset));
+	*view.get_at_mut(offset) = 0x1234;
+	assert_eq!(0x1234_u16, view.read(6));
+	assert!(matches!(ValidOffset::<u16>::try_new(3), None));
+	assert!(matches!(ValidOffset::<u16>::try_new(usize::MAX - 1), None));
+	assert!(matches!(view.try_get_at(ValidOffset::<u16>::new(8)), None));
+	assert!(matches!(view[1..].try_get_at(ValidOffset::<u16>::new(0)), None));
+	assert!(matches!(view.try_get_at_mut(ValidOffset::<u32>::new(8)), None));
+}
+
+#[test]
+fn test_policy_view() {
+	let mut data = TEST_DATA;
+	let view = PolicyView::<ZeroFill>::new_mut(DataView::from_mut(&mut data.1));
+	assert_eq!(view.read::<u8>(3), 3);
+	assert_eq!(view.read::<[u8; 3]>(6), [6, 7, 0]);
+	assert_eq!(view.read::<[u8; 2]>(100), [0, 0]);
+	view.write(6, &[0xff_u8; 4]);
+	view.write(100, &[0xff_u8; 4]);
+	assert_eq!(view.as_ref(), &[0, 1, 2, 3, 4, 5, 0xff, 0xff]);
+
+	let view = PolicyView::<Checked>::new(DataView::from(&TEST_DATA.1));
+	let mut dest = [0u8; 2];
+	view.read_into(6, &mut dest);
+	assert_eq!(dest, [6, 7]);
+
+	let view = unsafe { PolicyView::<TrustedUnchecked>::new(DataView::from(&TEST_DATA.1)) };
+	assert_eq!(view.read::<[u8; 2]>(2), [2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_policy_view_checked() {
+	let view = PolicyView::<Checked>::new(DataView::from(&TEST_DATA.1));
+	let _ = view.read::<u16>(7);
+}
+
+#[test]
+fn test_try_pod() {
+	use core::num::NonZeroU16;
+	let view = DataView::from(&TEST_DATA.1);
+	assert_eq!(view.try_read_valid::<bool>(0), Some(false));
+	assert_eq!(view.try_read_valid::<bool>(1), Some(true));
+	assert_eq!(view.try_read_valid::<bool>(2), None);
+	assert_eq!(view.try_read_valid::<bool>(8), None);
+	assert_eq!(view.try_read_valid::<u8>(2), Some(2));
+	assert_eq!(view.try_read_valid::<NonZeroU16>(1), NonZeroU16::new(u16::from_ne_bytes([1, 2])));
+	assert_eq!(view.try_get_valid::<bool>(1), Some(&true));
+	assert_eq!(view.try_get_valid::<bool>(7), None);
+
+	let chars = [0x41u32, 0xd800];
+	let view = DataView::from(&chars);
+	assert_eq!(view.try_get_valid::<char>(0), Some(&'A'));
+	assert_eq!(view.try_read_valid::<char>(4), None);
+	assert_eq!(view.try_get_valid::<char>(1), None);
+	assert_eq!(DataView::from(&[0u16; 2]).try_read_valid::<NonZeroU16>(2), None);
+}
+
+#[test]
+fn test_cast() {
+	use crate::cast::*;
+	assert_eq!(cast::<[u8; 4], u32>([1; 4]), 0x01010101);
+	assert_eq!(try_cast::<u32, u16>(0), Err(CastError::SizeMismatch));
+
+	let mut words = [0x01020304u32, 0x05060708];
+	let chunks: &[[u8; 4]] = try_cast_slice(&words).unwrap();
+	assert_eq!(chunks, &[0x01020304u32.to_ne_bytes(), 0x05060708u32.to_ne_bytes()]);
+	let bytes: &[u8] = try_cast_slice(&words).unwrap();
+	assert_eq!(bytes.len(), 8);
+	assert_eq!(try_cast_slice::<u8, u32>(&bytes[1..5]), Err(CastError::Misaligned));
+	assert_eq!(try_cast_slice::<u8, u32>(&bytes[..6]), Err(CastError::SizeMismatch));
+	assert_eq!(try_cast_slice::<u32, ()>(&words), Err(CastError::SizeMismatch));
+
+	let pair: &mut [u16; 2] = try_cast_mut(&mut words[0]).unwrap();
+	*pair = [0; 2];
+	assert_eq!(try_cast_ref::<u32, [u8; 4]>(&words[0]), Ok(&[0; 4]));
+	assert_eq!(try_cast_ref::<u32, u64>(&words[0]), Err(CastError::SizeMismatch));
+	let halves: &mut [u16] = try_cast_slice_mut(&mut words).unwrap();
+	assert_eq!(halves.len(), 4);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_cast_vec() {
+	use alloc::vec::Vec;
+	use crate::cast::*;
+
+	let mut pairs: Vec<[u16; 2]> = Vec::with_capacity(4);
+	pairs.extend_from_slice(&[[1, 2], [3, 4], [5, 6]]);
+	let ptr = pairs.as_ptr() as usize;
+	let mut halves: Vec<u16> = try_cast_vec(pairs).unwrap();
+	assert_eq!(halves, [1, 2, 3, 4, 5, 6]);
+	assert_eq!(halves.as_ptr() as usize, ptr);
+	assert_eq!(halves.capacity(), 8);
+
+	halves.push(7);
+	let (err, halves) = try_cast_vec::<u16, [u16; 2]>(halves).unwrap_err();
+	assert_eq!(err, CastError::SizeMismatch);
+	let (err, halves) = try_cast_vec::<u16, u32>(halves).unwrap_err();
+	assert_eq!(err, CastError::Misaligned);
+
+	let words: Vec<u32> = cast_vec(halves[..4].to_vec());
+	assert_eq!(words.len(), 2);
+	assert_eq!(bytes(&words[..]), bytes(&halves[..4]));
+	assert!(try_cast_vec::<u32, ()>(words).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn test_cast_vec_size_mismatch() {
+	let _: alloc::vec::Vec<u32> = crate::cast::cast_vec(alloc::vec![0u8; 7]);
+}
@@ -0,0 +1,111 @@
+use core::{num, ptr};
+use super::*;
+
+/// Types whose values can be transmuted from byte arrays of the same size after validation.
+///
+/// Unlike [`Pod`] not every bit pattern needs to be a valid instance of the type.
+/// This is the case for `bool`, `char`, the `NonZero` integers and fieldless enums.
+/// Every pod type is trivially `TryPod`.
+///
+/// ```
+/// let view = dataview::DataView::from(&[1u8, 2]);
+/// assert_eq!(view.try_read_valid::<bool>(0), Some(true));
+/// assert_eq!(view.try_read_valid::<bool>(1), None);
+/// ```
+///
+/// Fieldless enums implement this trait with its [derive macro](derive@TryPod).
+/// The enum must have a primitive integer repr which is not combined with other reprs changing its layout:
+///
+/// ```compile_fail
+/// #[derive(dataview::TryPod)]
+/// #[repr(align(4))]
+/// #[repr(u8)]
+/// enum Aligned {
+/// 	A,
+/// }
+/// ```
+///
+/// # Safety
+///
+/// `Bits` must have the same size as `Self`.
+/// It must be safe to transmute from `Bits` to `Self` whenever `is_valid` returns `true`.
+pub unsafe trait TryPod: Sized + 'static {
+	/// Pod type holding the raw bits of the type.
+	type Bits: Pod;
+	/// Returns whether the bits are a valid instance of the type.
+	fn is_valid(bits: &Self::Bits) -> bool;
+}
+
+unsafe impl<T: Pod> TryPod for T {
+	type Bits = T;
+	#[inline]
+	fn is_valid(_bits: &T) -> bool {
+		true
+	}
+}
+
+unsafe impl TryPod for bool {
+	type Bits = u8;
+	#[inline]
+	fn is_valid(bits: &u8) -> bool {
+		*bits <= 1
+	}
+}
+
+unsafe impl TryPod for char {
+	type Bits = u32;
+	#[inline]
+	fn is_valid(bits: &u32) -> bool {
+		char::from_u32(*bits).is_some()
+	}
+}
+
+macro_rules! impl_try_pod_nonzero {
+	($($ty:ty => $bits:ty),*) => {
+		$(
+			unsafe impl TryPod for $ty {
+				type Bits = $bits;
+				#[inline]
+				fn is_valid(bits: &$bits) -> bool {
+					*bits != 0
+				}
+			}
+		)*
+	};
+}
+
+impl_try_pod_nonzero!(
+	num::NonZeroI8 => i8, num::NonZeroI16 => i16, num::NonZeroI32 => i32, num::NonZeroI64 => i64, num::NonZeroI128 => i128, num::NonZeroIsize => isize,
+	num::NonZeroU8 => u8, num::NonZeroU16 => u16, num::NonZeroU32 => u32, num::NonZeroU64 => u64, num::NonZeroU128 => u128, num::NonZeroUsize => usize
+);
+
+/// Reads and gets values which are validated before use.
+impl DataView {
+	/// Reads a (potentially unaligned) value from the view.
+	///
+	/// Errors if the bytes are not a valid instance of the type.
+	#[inline]
+	pub fn try_read_valid<T: TryPod>(&self, offset: usize) -> Option<T> {
+		let bits: T::Bits = self.try_read(offset)?;
+		if !T::is_valid(&bits) {
+			return None;
+		}
+		unsafe {
+			Some(ptr::read(&bits as *const T::Bits as *const T))
+		}
+	}
+	/// Gets an aligned reference into the view.
+	///
+	/// Errors if the final pointer is misaligned for the given type or if the bytes are not a valid instance of the type.
+	#[inline]
+	pub fn try_get_valid<T: TryPod>(&self, offset: usize) -> Option<&T> {
+		let bits: &T::Bits = self.try_get(offset)?;
+		let ptr = bits as *const T::Bits as *const T;
+		if !is_aligned(ptr) || !T::is_valid(bits) {
+			return None;
+		}
+		unsafe {
+			Some(&*ptr)
+		}
+	}
+}
@@ -0,0 +1,79 @@
+use core::{fmt, ptr};
+use super::*;
+
+/// Wrapper with an alignment of 1.
+///
+/// Allows getting references to values at unaligned offsets and expressing packed on-disk structs.
+/// The inner value cannot be referenced directly, it is copied in and out with [`get`](Unalign::get) and [`set`](Unalign::set).
+///
+/// ```
+/// use dataview::{DataView, Unalign};
+///
+/// let view = DataView::from(&[0u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+/// let value = view.get::<Unalign<u64>>(1);
+/// assert_eq!(value.get(), u64::from_ne_bytes([1, 0, 0, 0, 0, 0, 0, 0]));
+/// ```
+#[repr(C, packed)]
+pub struct Unalign<T>(T);
+
+unsafe impl<T: Pod> Pod for Unalign<T> {}
+
+impl<T> Unalign<T> {
+	/// Wraps the value.
+	#[inline]
+	pub const fn new(value: T) -> Unalign<T> {
+		Unalign(value)
+	}
+	/// Unwraps the value.
+	#[inline]
+	pub fn into_inner(self) -> T {
+		let this = mem::ManuallyDrop::new(self);
+		unsafe { ptr::read_unaligned(ptr::addr_of!(this.0)) }
+	}
+	/// Returns a copy of the value.
+	#[inline]
+	pub fn get(&self) -> T where T: Copy {
+		unsafe { ptr::read_unaligned(ptr::addr_of!(self.0)) }
+	}
+	/// Replaces the value.
+	#[inline]
+	pub fn set(&mut self, value: T) {
+		unsafe { ptr::write_unaligned(ptr::addr_of_mut!(self.0), value) }
+	}
+}
+
+impl<T: Copy> Copy for Unalign<T> {}
+impl<T: Copy> Clone for Unalign<T> {
+	#[inline]
+	fn clone(&self) -> Unalign<T> {
+		*self
+	}
+}
+
+impl<T: Default> Default for Unalign<T> {
+	#[inline]
+	fn default() -> Unalign<T> {
+		Unalign(T::default())
+	}
+}
+
+impl<T> From<T> for Unalign<T> {
+	#[inline]
+	fn from(value: T) -> Unalign<T> {
+		Unalign(value)
+	}
+}
+
+impl<T: Copy + PartialEq> PartialEq for Unalign<T> {
+	#[inline]
+	fn eq(&self, other: &Unalign<T>) -> bool {
+		self.get() == other.get()
+	}
+}
+impl<T: Copy + Eq> Eq for Unalign<T> {}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for Unalign<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("Unalign").field(&self.get()).finish()
+	}
+}
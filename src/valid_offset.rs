@@ -0,0 +1,116 @@
+use core::{fmt, marker::PhantomData};
+use super::*;
+use crate::data_view::invalid_offset;
+
+/// Offset of an aligned `T` with its arithmetic checked up front.
+///
+/// A valid offset is a multiple of the alignment of `T` and its end does not overflow.
+/// Accessing a view through it only needs to compare the end against the length of the view
+/// and check the alignment of the view itself, which makes it cheap to reuse the same offset in hot loops.
+///
+/// ```
+/// use dataview::{DataView, ValidOffset};
+///
+/// const COUNT: ValidOffset<u32> = ValidOffset::new(4);
+///
+/// let records = [[1u32, 10], [2, 20], [3, 30]];
+/// let total: u32 = records.iter()
+/// 	.map(|record| *DataView::from(record).get_at(COUNT))
+/// 	.sum();
+/// assert_eq!(total, 60);
+/// ```
+pub struct ValidOffset<T> {
+	offset: usize,
+	end: usize,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> ValidOffset<T> {
+	/// Checks the offset for an aligned `T`.
+	///
+	/// # Panics
+	///
+	/// Panics if the offset is not a multiple of the alignment of `T` or if its end overflows.
+	#[track_caller]
+	#[inline]
+	pub const fn new(offset: usize) -> ValidOffset<T> {
+		match ValidOffset::try_new(offset) {
+			Some(valid) => valid,
+			None => panic!("invalid offset"),
+		}
+	}
+	/// Checks the offset for an aligned `T`.
+	#[inline]
+	pub const fn try_new(offset: usize) -> Option<ValidOffset<T>> {
+		if offset & (mem::align_of::<T>() - 1) != 0 {
+			return None;
+		}
+		match offset.checked_add(mem::size_of::<T>()) {
+			Some(end) => Some(ValidOffset { offset, end, _phantom: PhantomData }),
+			None => None,
+		}
+	}
+	/// Returns the offset.
+	#[inline]
+	pub const fn get(self) -> usize {
+		self.offset
+	}
+}
+
+impl<T> Copy for ValidOffset<T> {}
+impl<T> Clone for ValidOffset<T> {
+	#[inline]
+	fn clone(&self) -> ValidOffset<T> {
+		*self
+	}
+}
+impl<T> fmt::Debug for ValidOffset<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("ValidOffset").field(&self.offset).finish()
+	}
+}
+
+/// Gets an aligned reference into the view with a valid offset.
+impl DataView {
+	/// Gets an aligned reference into the view with a valid offset.
+	#[inline]
+	pub fn try_get_at<T: AnyBitPattern>(&self, offset: ValidOffset<T>) -> Option<&T> {
+		let base = self.as_ref().as_ptr();
+		if offset.end > self.len() || !is_aligned(base as *const T) {
+			return None;
+		}
+		unsafe {
+			Some(&*(base.add(offset.offset) as *const T))
+		}
+	}
+	/// Gets an aligned reference into the view with a valid offset.
+	#[track_caller]
+	#[inline]
+	pub fn get_at<T: AnyBitPattern>(&self, offset: ValidOffset<T>) -> &T {
+		match self.try_get_at(offset) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+	/// Gets an aligned mutable reference into the view with a valid offset.
+	#[inline]
+	pub fn try_get_at_mut<T: Pod>(&mut self, offset: ValidOffset<T>) -> Option<&mut T> {
+		let len = self.len();
+		let base = self.as_mut().as_mut_ptr();
+		if offset.end > len || !is_aligned(base as *const T) {
+			return None;
+		}
+		unsafe {
+			Some(&mut *(base.add(offset.offset) as *mut T))
+		}
+	}
+	/// Gets an aligned mutable reference into the view with a valid offset.
+	#[track_caller]
+	#[inline]
+	pub fn get_at_mut<T: Pod>(&mut self, offset: ValidOffset<T>) -> &mut T {
+		match self.try_get_at_mut(offset) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
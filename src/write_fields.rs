@@ -0,0 +1,50 @@
+use super::*;
+
+/// Types which can be written into a view field by field.
+///
+/// This allows writing structs which contain padding and thus cannot implement [`AsBytes`].
+/// Each field is written at its offset, the padding bytes in the view are left unchanged.
+///
+/// Implement this trait with its [derive macro](derive@WriteFields).
+///
+/// ```
+/// #[derive(dataview::WriteFields)]
+/// #[repr(C)]
+/// struct Padded {
+/// 	byte: u8,
+/// 	word: u32,
+/// }
+///
+/// let mut buf = [0xffu8; 8];
+/// let view = dataview::DataView::from_mut(&mut buf);
+/// view.write_fields(0, &Padded { byte: 1, word: 0 });
+/// assert_eq!(buf, [1, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+/// ```
+pub trait WriteFields: Sized {
+	/// Writes the fields into a view with the same size as `Self`.
+	fn write_fields(&self, view: &mut DataView);
+}
+
+/// Writes a value into the view field by field.
+impl DataView {
+	/// Writes a value into the view field by field.
+	///
+	/// The padding bytes in the view are left unchanged.
+	#[inline]
+	pub fn try_write_fields<T: WriteFields>(&mut self, offset: usize, value: &T) -> Option<()> {
+		let view = self.index_mut(offset..offset.checked_add(mem::size_of::<T>())?)?;
+		value.write_fields(view);
+		Some(())
+	}
+	/// Writes a value into the view field by field.
+	///
+	/// The padding bytes in the view are left unchanged.
+	#[track_caller]
+	#[inline]
+	pub fn write_fields<T: WriteFields>(&mut self, offset: usize, value: &T) {
+		match self.try_write_fields(offset, value) {
+			Some(()) => (),
+			None => crate::data_view::invalid_offset(),
+		}
+	}
+}
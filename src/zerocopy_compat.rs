@@ -0,0 +1,73 @@
+use super::*;
+use crate::data_view::invalid_offset;
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+/// Wrapper bridging `zerocopy` types into dataview Pod types.
+///
+/// Wrapping a type which is [`FromBytes`], [`IntoBytes`] and [`Immutable`] makes it a dataview [`Pod`].
+/// Alignment is preserved, wrap the value in [`Unalign`] to read it at any offset.
+///
+/// ```
+/// use dataview::{DataView, Zerocopy};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable)]
+/// #[repr(C)]
+/// struct Header {
+/// 	magic: [u8; 4],
+/// 	len: u32,
+/// }
+///
+/// let header = Header { magic: *b"DATA", len: 12 };
+/// let view = DataView::from_zerocopy(&header);
+/// assert_eq!(view.read::<Zerocopy<Header>>(0).0, header);
+/// assert_eq!(view.read_zerocopy::<u32>(4), 12);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Zerocopy<T>(pub T);
+
+unsafe impl<T: FromBytes + IntoBytes + Immutable + 'static> Pod for Zerocopy<T> {}
+
+impl<T> From<T> for Zerocopy<T> {
+	#[inline]
+	fn from(value: T) -> Zerocopy<T> {
+		Zerocopy(value)
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Views `zerocopy` types.
+impl DataView {
+	/// Returns a data view into the bytes of a `zerocopy` type.
+	#[inline]
+	pub fn from_zerocopy<T: ?Sized + IntoBytes + Immutable>(v: &T) -> &DataView {
+		DataView::from(v.as_bytes())
+	}
+	/// Returns a mutable data view into the bytes of a `zerocopy` type.
+	#[inline]
+	pub fn from_zerocopy_mut<T: ?Sized + FromBytes + IntoBytes>(v: &mut T) -> &mut DataView {
+		DataView::from_mut(v.as_mut_bytes())
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Reads `zerocopy` types.
+impl DataView {
+	/// Reads a (potentially unaligned) `zerocopy` value from the view.
+	#[inline]
+	pub fn try_read_zerocopy<T: FromBytes>(&self, offset: usize) -> Option<T> {
+		let bytes = self.as_ref().get(offset..)?;
+		T::read_from_prefix(bytes).ok().map(|(value, _)| value)
+	}
+	/// Reads a (potentially unaligned) `zerocopy` value from the view.
+	#[track_caller]
+	#[inline]
+	pub fn read_zerocopy<T: FromBytes>(&self, offset: usize) -> T {
+		match self.try_read_zerocopy(offset) {
+			Some(value) => value,
+			None => invalid_offset(),
+		}
+	}
+}
@@ -0,0 +1,86 @@
+use core::marker::PhantomData;
+use super::*;
+
+/// Iterator over corresponding records of two views in lockstep.
+///
+/// Created by [`DataView::zip_records`].
+#[derive(Clone)]
+pub struct ZipRecords<'a, A, B> {
+	a: &'a DataView,
+	b: &'a DataView,
+	a_stride: usize,
+	b_stride: usize,
+	index: usize,
+	len: usize,
+	_phantom: PhantomData<fn() -> (A, B)>,
+}
+
+// Number of records with the given size and stride that fit in the view
+#[track_caller]
+pub(crate) fn record_count(len: usize, size: usize, stride: usize) -> usize {
+	assert!(stride != 0, "stride must be nonzero");
+	if len < size { 0 } else { (len - size) / stride + 1 }
+}
+
+impl DataView {
+	/// Iterates over corresponding records of two views in lockstep.
+	///
+	/// The `i`th item is the record at offset `i * stride` in this view paired with the record at offset `i * other_stride` in the other view.
+	/// The records are read by value and may be unaligned. Iteration stops when either view runs out of records.
+	///
+	/// ```
+	/// let old = [1u32, 2, 3];
+	/// let new = [[1u32, 0], [5, 0], [3, 0]];
+	///
+	/// let old = dataview::DataView::from(&old);
+	/// let new = dataview::DataView::from(&new);
+	///
+	/// let changed = old.zip_records::<u32, u32>(4, new, 8)
+	/// 	.position(|(a, b)| a != b);
+	/// assert_eq!(changed, Some(1));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if either stride is zero.
+	#[track_caller]
+	#[inline]
+	pub fn zip_records<'a, A: Pod, B: Pod>(&'a self, stride: usize, other: &'a DataView, other_stride: usize) -> ZipRecords<'a, A, B> {
+		let a_len = record_count(self.len(), mem::size_of::<A>(), stride);
+		let b_len = record_count(other.len(), mem::size_of::<B>(), other_stride);
+		ZipRecords {
+			a: self,
+			b: other,
+			a_stride: stride,
+			b_stride: other_stride,
+			index: 0,
+			len: cmp::min(a_len, b_len),
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<'a, A: Pod, B: Pod> Iterator for ZipRecords<'a, A, B> {
+	type Item = (A, B);
+	#[inline]
+	fn next(&mut self) -> Option<(A, B)> {
+		if self.index >= self.len {
+			return None;
+		}
+		let i = self.index;
+		self.index += 1;
+		unsafe {
+			let a = self.a.read_unchecked(i * self.a_stride);
+			let b = self.b.read_unchecked(i * self.b_stride);
+			Some((a, b))
+		}
+	}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len - self.index;
+		(len, Some(len))
+	}
+}
+
+impl<'a, A: Pod, B: Pod> ExactSizeIterator for ZipRecords<'a, A, B> {}
+impl<'a, A: Pod, B: Pod> core::iter::FusedIterator for ZipRecords<'a, A, B> {}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use dataview::{Pod, FieldOffsets};
+use dataview::{Pod, FieldOffsets, WriteFields};
 
 #[derive(Pod)]
 #[repr(C)]
@@ -79,3 +79,297 @@ struct Tuple4(i32, f32,);
 #[derive(Pod)]
 #[repr(C)]
 struct Unit;
+
+#[derive(WriteFields)]
+#[repr(C)]
+struct Padded {
+	byte: u8,
+	/// doc comment
+	flag: bool,
+	word: u32,
+	ch: char,
+}
+
+#[derive(WriteFields)]
+struct Empty {}
+
+#[derive(WriteFields)]
+#[repr(C)]
+struct Tagged<T: Copy>(u8, T);
+
+#[test]
+fn write_fields() {
+	let mut buf = [0xffu8; 8];
+	dataview::DataView::from_mut(&mut buf).write_fields(0, &Tagged(1u8, 2u32));
+	assert_eq!(buf[..4], [1, 0xff, 0xff, 0xff]);
+	assert_eq!(buf[4..], 2u32.to_ne_bytes());
+
+	let mut buf = [0u8; 12];
+	let padded = Padded { byte: 1, flag: true, word: 3, ch: 'x' };
+	dataview::DataView::from_mut(&mut buf).write_fields(0, &padded);
+	assert_eq!(buf[..2], [1, 1]);
+	assert_eq!(buf[8..], ('x' as u32).to_ne_bytes());
+}
+
+#[derive(Pod)]
+#[repr(C)]
+struct Vec3<T> {
+	x: T,
+	y: T,
+	z: T,
+}
+
+#[derive(Pod)]
+#[repr(C)]
+struct Matrix<T: Copy> {
+	rows: [T; 2],
+	cols: [T; 2],
+}
+
+#[derive(Pod)]
+#[repr(transparent)]
+struct Wrapper<T>(T);
+
+#[derive(Pod)]
+#[repr(C)]
+struct Pair<T, >(T, T, );
+
+#[test]
+fn generic_structs() {
+	let v: Vec3<f32> = dataview::zeroed();
+	assert_eq!(dataview::bytes(&v).len(), 12);
+	let w = Wrapper(Vec3 { x: 1u16, y: 2, z: 3 });
+	assert_eq!(dataview::bytes(&w).len(), 6);
+}
+
+#[derive(Pod)]
+#[repr(C)]
+union Register {
+	value: u32,
+	/// doc comment
+	bytes: [u8; 4],
+	halves: [u16; 2],
+}
+
+#[test]
+fn unions() {
+	let mut reg: Register = dataview::zeroed();
+	reg.value = 0x01020304;
+	assert_eq!(dataview::bytes(&reg), &0x01020304u32.to_ne_bytes());
+}
+
+#[derive(Pod)]
+#[repr(C, packed)]
+struct Packed {
+	tag: u8,
+	pub value: u32,
+	pub(crate) pair: [u16; 2],
+}
+
+#[derive(Pod)]
+#[repr(packed, C)]
+struct Packed2 {
+	a: u8,
+	b: u64,
+}
+
+#[derive(Pod)]
+#[repr(C)]
+#[repr(packed(2))]
+struct Packed3(u8, u8, u32);
+
+#[derive(Pod)]
+#[repr(C, packed)]
+struct PackedGeneric<T>(u8, T);
+
+#[derive(Pod)]
+#[repr(C, packed(2))]
+struct PackedPair<T>(T, T);
+
+#[test]
+fn packed_structs() {
+	let mut packed: Packed = dataview::zeroed();
+	packed.value = 0x01020304;
+	assert_eq!(packed.value(), 0x01020304);
+	assert_eq!(packed.pair(), [0, 0]);
+	assert_eq!(core::mem::size_of::<Packed>(), 9);
+	assert_eq!(core::mem::size_of::<Packed2>(), 9);
+	assert_eq!(core::mem::size_of::<Packed3>(), 6);
+	assert_eq!(core::mem::size_of::<PackedGeneric<u32>>(), 5);
+	assert_eq!(core::mem::size_of::<PackedPair<u32>>(), 8);
+}
+
+mod renamed {
+	pub use dataview as reexport;
+}
+
+#[derive(Pod, FieldOffsets)]
+#[pod(crate = "crate::renamed::reexport")]
+#[repr(C)]
+struct Renamed {
+	field: u32,
+}
+
+const _: [(); 0] = [(); Renamed::FIELD_OFFSETS.field];
+
+#[derive(Pod)]
+#[pod(size = 16, align = 8)]
+#[repr(C)]
+struct Header {
+	magic: u32,
+	len: u32,
+	offset: u64,
+}
+
+#[derive(Pod)]
+#[pod(size = core::mem::size_of::<u32>())]
+#[repr(C)]
+union Overlay {
+	word: u32,
+	bytes: [u8; 4],
+}
+
+#[derive(Pod, FieldOffsets)]
+#[repr(C, packed)]
+struct PackedOffsets {
+	byte: u8,
+	word: u32,
+	long: u64,
+}
+
+const _: [(); 1] = [(); PackedOffsets::FIELD_OFFSETS.word];
+const _: [(); 5] = [(); PackedOffsets::FIELD_OFFSETS.long];
+
+#[test]
+fn field_spans() {
+	assert_eq!(Struct6::FIELD_SPANS.field1, 0..4);
+	assert_eq!(Struct6::FIELD_SPANS.field2, 4..8);
+	assert_eq!(PackedOffsets::FIELD_SPANS.long, 5..13);
+
+	let value = Struct6 { field1: 1, field2: 2.0 };
+	let view = dataview::DataView::from(&value);
+	assert_eq!(view[Struct6::FIELD_SPANS.field2].read::<f32>(0), 2.0);
+}
+
+#[test]
+fn typed_offsets() {
+	let mut value = Struct6 { field1: 1, field2: 2.0 };
+	let view = dataview::DataView::from_mut(&mut value);
+	assert_eq!(view.field(Struct6::TYPED_OFFSETS.field2), 2.0);
+	*view.field_mut(Struct6::TYPED_OFFSETS.field1) = 42;
+	assert_eq!(value.field1, 42);
+	assert_eq!(PackedOffsets::TYPED_OFFSETS.long.span(), 5..13);
+	assert_eq!(dataview::DataView::from(&[0u8; 2][..]).try_field(Struct6::TYPED_OFFSETS.field1), None);
+}
+
+#[derive(Pod, FieldOffsets)]
+#[pod(fields)]
+#[repr(C)]
+struct Reflect {
+	magic: [u8; 4],
+	r#type: u16,
+	len: u16,
+}
+
+#[test]
+fn field_reflection() {
+	assert_eq!(Reflect::FIELDS.len(), 3);
+	let field = Reflect::field_by_name("type").unwrap();
+	assert_eq!((field.offset, field.size), (4, 2));
+	assert_eq!(Reflect::field_by_name("len").unwrap().span(), 6..8);
+	assert!(Reflect::field_by_name("crc").is_none());
+}
+
+#[derive(Pod, FieldOffsets)]
+#[repr(C)]
+pub struct Inner {
+	pub x: u32,
+	pub y: u32,
+}
+
+#[derive(Pod, FieldOffsets)]
+#[repr(C)]
+pub struct Outer {
+	pub tag: u64,
+	#[pod(nested)]
+	pub inner: Inner,
+	#[pod(nested)]
+	pub other: Inner,
+}
+
+const _: [(); 12] = [(); Outer::FIELD_OFFSETS.inner.y];
+const _: [(); 16] = [(); Outer::FIELD_OFFSETS.other.x];
+const _: [(); 4] = [(); Inner::FIELD_OFFSETS.y];
+const _: [(); 8] = [(); Outer::offset_of_inner()];
+
+struct Prefix<const N: usize>([u8; N]);
+
+#[test]
+fn offset_accessors() {
+	let prefix = Prefix::<{ Outer::offset_of_other() }>([0; 16]);
+	assert_eq!(prefix.0.len(), 16);
+	assert_eq!(Reflect::offset_of_type(), 4);
+	assert_eq!(Pair3::<u16>::offset_of_second(), 6);
+}
+
+#[test]
+fn nested_offsets() {
+	assert_eq!(Outer::FIELD_SPANS.other, 16..24);
+}
+
+struct Patch {
+	offsets: InnerFieldOffsets,
+	base: usize,
+}
+
+#[test]
+fn named_offsets() {
+	let patches = [
+		Patch { offsets: Outer::FIELD_OFFSETS.inner, base: 0 },
+		Patch { offsets: Inner::FIELD_OFFSETS, base: 16 },
+	];
+	assert_eq!(patches[0].base + patches[0].offsets.y, 12);
+	assert_eq!(patches[1].base + patches[1].offsets.x, 16);
+	let OuterFieldOffsets { tag, .. } = Outer::FIELD_OFFSETS;
+	assert_eq!(tag, 0);
+	let InnerFieldSpans { y, .. } = Inner::FIELD_SPANS;
+	assert_eq!(y, 4..8);
+	let typed: InnerTypedOffsets = Inner::TYPED_OFFSETS;
+	assert_eq!(typed.y.offset(), 4);
+}
+
+#[derive(Pod, FieldOffsets)]
+#[pod(fields)]
+#[repr(C)]
+struct Pair3<T> where T: Copy {
+	first: [T; 3],
+	second: [T; 3],
+}
+
+#[test]
+fn generic_offsets() {
+	assert_eq!(Pair3::<u8>::FIELD_OFFSETS.second, 3);
+	assert_eq!(Pair3::<f64>::FIELD_OFFSETS.second, 24);
+	assert_eq!(Pair3::<u16>::FIELD_SPANS.second, 6..12);
+	assert_eq!(Pair3::<u32>::field_by_name("second").unwrap().size, 12);
+}
+
+#[derive(dataview::Layout)]
+#[repr(C)]
+struct Described<T> {
+	flag: bool,
+	value: T,
+}
+
+#[derive(dataview::Layout)]
+struct DescribedTuple(u8, u64);
+
+#[test]
+fn layout() {
+	use dataview::Layout;
+	assert_eq!(Described::<u32>::SIZE, 8);
+	assert_eq!(Described::<u16>::ALIGN, 2);
+	assert_eq!(Described::<u64>::fields()[1].span(), 8..16);
+	assert_eq!(DescribedTuple::FIELDS[1].name, "1");
+	assert_eq!(DescribedTuple::FIELDS[1].size, 8);
+}
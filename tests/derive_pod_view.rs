@@ -0,0 +1,61 @@
+use dataview::{DataView, PodView};
+
+#[derive(PodView)]
+#[repr(C)]
+pub struct Header {
+	pub magic: u32,
+	pub len: u16,
+	flags: [u8; 2],
+	/// doc comment
+	pub(crate) version: u16,
+}
+
+#[test]
+fn views() {
+	let mut bytes = [0u8; 16];
+	let view = DataView::from_mut(&mut bytes[1..]);
+	let mut header = HeaderViewMut::new(view).unwrap();
+	header.set_magic(0x01020304);
+	header.set_len(42);
+	header.set_flags([1, 2]);
+	header.set_version(3);
+	assert_eq!(header.as_data_view_mut().len(), core::mem::size_of::<Header>());
+
+	let header = HeaderView::new(DataView::from(&bytes[1..])).unwrap();
+	assert_eq!(header.magic(), 0x01020304);
+	assert_eq!(header.len(), 42);
+	assert_eq!(header.flags(), [1, 2]);
+	assert_eq!(header.version(), 3);
+	assert_eq!(bytes[1..5], 0x01020304u32.to_ne_bytes());
+	assert_eq!(bytes[7], 1);
+
+	assert!(HeaderView::new(DataView::from(&bytes[..11])).is_none());
+}
+
+#[derive(PodView)]
+#[pod(endian = "be")]
+#[repr(C)]
+struct UdpHeader {
+	src_port: u16,
+	dst_port: u16,
+	#[pod(endian = "le")]
+	len: u16,
+	#[pod(endian = "ne")]
+	checksum: [u8; 2],
+	value: f32,
+}
+
+#[test]
+fn endian() {
+	let mut bytes = [0u8; 12];
+	let mut header = UdpHeaderViewMut::new(DataView::from_mut(&mut bytes)).unwrap();
+	header.set_src_port(0x0102);
+	header.set_dst_port(0x0304);
+	header.set_len(0x0506);
+	header.set_checksum([7, 8]);
+	header.set_value(1.0);
+	assert_eq!(header.src_port(), 0x0102);
+	assert_eq!(header.len(), 0x0506);
+	assert_eq!(header.as_view().value(), 1.0);
+	assert_eq!(bytes, [1, 2, 3, 4, 6, 5, 7, 8, 0x3f, 0x80, 0, 0]);
+}
@@ -0,0 +1,42 @@
+use dataview::{DataView, TryPod};
+
+#[derive(TryPod, Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+enum Opcode {
+	Nop,
+	Load = 4,
+	/// doc comment
+	Store,
+}
+
+#[derive(TryPod, Copy, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+#[repr(i16)]
+enum Signed {
+	Negative = -1,
+	Positive = 1,
+}
+
+#[derive(TryPod, Copy, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+#[repr(u32)]
+enum Tag {
+	Tag = 0x1234,
+}
+
+#[test]
+fn fieldless_enums() {
+	let view = DataView::from(&[0u8, 4, 5, 1]);
+	assert_eq!(view.try_read_valid(0), Some(Opcode::Nop));
+	assert_eq!(view.try_read_valid(1), Some(Opcode::Load));
+	assert_eq!(view.try_read_valid(2), Some(Opcode::Store));
+	assert_eq!(view.try_read_valid::<Opcode>(3), None);
+
+	let view = DataView::from(&[-1i16, 0]);
+	assert_eq!(view.try_read_valid(0), Some(Signed::Negative));
+	assert_eq!(view.try_read_valid::<Signed>(2), None);
+
+	let view = DataView::from(&[0x1234u32, 0]);
+	assert_eq!(view.try_read_valid(0), Some(Tag::Tag));
+	assert_eq!(view.try_read_valid::<Tag>(4), None);
+}
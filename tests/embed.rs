@@ -0,0 +1,130 @@
+use core::mem;
+
+dataview::embed! {
+	static TABLE: [u32] = "data/table.bin";
+}
+
+dataview::embed! {
+	#[allow(dead_code)]
+	pub static BYTES: [u8] = "data/table.bin";
+}
+
+dataview::embed! {
+	static PAIRS: [[u16; 2]] = "data/table.bin";
+}
+
+dataview::embed! {
+	static TRUNCATED: [u64; truncate] = "data/odd.bin";
+}
+
+dataview::embed! {
+	static PADDED: [u64; pad] = "data/odd.bin";
+}
+
+dataview::embed! {
+	static ROOTED: [u32; pad] = manifest_dir("tests/data/table.bin");
+}
+
+dataview::embed! {
+	static ROOTED_CONFIG: Config = manifest_dir("tests/data/table.bin");
+}
+
+dataview::embed! {
+	static LITTLE: [u32; le] = "data/table.bin";
+}
+
+dataview::embed! {
+	static BIG: [u32; be] = "data/table.bin";
+}
+
+dataview::embed! {
+	static BIG16: [u16; be] = "data/table.bin";
+}
+
+dataview::embed_files! {
+	static ASSETS = {
+		"odd" => "data/odd.bin",
+		"table" => manifest_dir("tests/data/table.bin"),
+		"again" => "data/odd.bin",
+	};
+}
+
+#[derive(dataview::Pod)]
+#[repr(C)]
+struct Config {
+	version: u32,
+	flags: u32,
+	limits: [u32; 2],
+}
+
+dataview::embed! {
+	/// Configuration block.
+	static CONFIG: Config = "data/table.bin";
+}
+
+dataview::embed! {
+	static WORD: u128 = "data/table.bin";
+}
+
+#[test]
+fn embed_array() {
+	assert_eq!(TABLE.map(u32::from_le), [1, 2, 3, 4]);
+	assert_eq!(BYTES.len(), 16);
+	assert_eq!(PAIRS.len(), 4);
+	assert_eq!(mem::align_of_val(&TABLE), mem::align_of::<u32>());
+	assert_eq!(TABLE.as_ptr() as usize % mem::align_of::<u32>(), 0);
+}
+
+#[test]
+fn embed_value() {
+	assert_eq!(u32::from_le(CONFIG.version), 1);
+	assert_eq!(u32::from_le(CONFIG.flags), 2);
+	assert_eq!(CONFIG.limits.map(u32::from_le), [3, 4]);
+	assert_eq!(WORD.to_ne_bytes()[..8], [1, 0, 0, 0, 2, 0, 0, 0]);
+}
+
+#[test]
+fn embed_policy() {
+	assert_eq!(TRUNCATED.len(), 1);
+	assert_eq!(PADDED.len(), 2);
+	assert_eq!(TRUNCATED[0].to_ne_bytes(), *b"dataview");
+	assert_eq!(PADDED[1].to_ne_bytes(), *b"!\0\0\0\0\0\0\0");
+}
+
+#[test]
+fn embed_manifest_dir() {
+	assert_eq!(ROOTED, TABLE);
+	assert_eq!(ROOTED_CONFIG.limits, CONFIG.limits);
+}
+
+#[test]
+fn embed_endian() {
+	assert_eq!(LITTLE, [1, 2, 3, 4]);
+	assert_eq!(BIG, [0x01000000, 0x02000000, 0x03000000, 0x04000000]);
+	assert_eq!(BIG16, [0x0100, 0, 0x0200, 0, 0x0300, 0, 0x0400, 0]);
+}
+
+#[test]
+fn include_pod() {
+	let view = dataview::include_pod!("data/table.bin");
+	assert_eq!(view.len(), 16);
+	assert_eq!(view.read::<u32>(12), u32::from_le(4));
+
+	let view = dataview::include_pod!(manifest_dir("tests/data/odd.bin"), dataview::A4096);
+	assert_eq!(view.as_ref(), b"dataview!");
+	assert_eq!(view.as_ref().as_ptr() as usize % 4096, 0);
+	assert_eq!(view.try_get::<u64>(0), Some(&u64::from_ne_bytes(*b"dataview")));
+}
+
+#[test]
+fn embed_files() {
+	let names: Vec<_> = ASSETS.iter().map(|(name, _)| name).collect();
+	assert_eq!(names, ["odd", "table", "again"]);
+	let offsets: Vec<_> = ASSETS.entries().iter().map(|entry| (entry.offset, entry.len)).collect();
+	assert_eq!(offsets, [(0, 9), (16, 16), (32, 9)]);
+	assert_eq!(ASSETS.data().len(), 48);
+	assert_eq!(ASSETS.data().as_ref().as_ptr() as usize % dataview::EmbeddedDir::ALIGN, 0);
+	assert_eq!(ASSETS.get("again").unwrap().as_ref(), b"dataview!");
+	assert_eq!(ASSETS.get("table").unwrap().as_ref(), &TABLE.map(u32::to_ne_bytes).concat()[..]);
+	assert!(ASSETS.get("none").is_none());
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "mmap")]
+
+use std::fs::{self, File};
+use dataview::MappedView;
+
+#[test]
+fn read_only_and_copy_on_write() {
+	let path = std::env::temp_dir().join(format!("dataview-mapped-{}.bin", std::process::id()));
+	fs::write(&path, [1u8, 0, 0, 0, 2, 0, 0, 0]).unwrap();
+	let file = File::open(&path).unwrap();
+
+	let mut view = unsafe { MappedView::map(&file).unwrap() };
+	assert!(!view.is_copy_on_write());
+	assert_eq!(view.len(), 8);
+	assert_eq!(view.read::<[u8; 2]>(3), [0, 2]);
+	assert!(view.as_data_view_mut().is_none());
+
+	let mut copy = unsafe { MappedView::map_copy(&file).unwrap() };
+	copy.as_data_view_mut().unwrap().write(0, &9u8);
+	assert_eq!(copy.read::<u8>(0), 9);
+	assert_eq!(view.read::<u8>(0), 1);
+
+	drop((view, copy, file));
+	assert_eq!(fs::read(&path).unwrap()[0], 1);
+	fs::remove_file(&path).unwrap();
+}
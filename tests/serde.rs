@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+
+use dataview::{DataView, Pod};
+use serde::{Deserialize, Serialize};
+use serde::de::value::{BorrowedBytesDeserializer, Error};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token};
+
+#[derive(Pod, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+struct Pair {
+	a: u16,
+	b: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Message {
+	#[serde(with = "dataview::serde_pod")]
+	pair: Pair,
+}
+
+#[test]
+fn pod_adapter() {
+	let message = Message { pair: Pair { a: 1, b: 2 } };
+	let bytes: &'static [u8] = Box::leak(dataview::bytes(&message.pair).to_vec().into_boxed_slice());
+	assert_tokens(&message, &[
+		Token::Struct { name: "Message", len: 1 },
+		Token::Str("pair"),
+		Token::Bytes(bytes),
+		Token::StructEnd,
+	]);
+	assert_de_tokens(&message, &[
+		Token::Struct { name: "Message", len: 1 },
+		Token::Str("pair"),
+		Token::Seq { len: Some(4) },
+		Token::U8(bytes[0]), Token::U8(bytes[1]), Token::U8(bytes[2]), Token::U8(bytes[3]),
+		Token::SeqEnd,
+		Token::StructEnd,
+	]);
+	assert_de_tokens_error::<Message>(&[
+		Token::Struct { name: "Message", len: 1 },
+		Token::Str("pair"),
+		Token::Bytes(&[1, 2, 3]),
+	], "invalid length 3, expected 4 bytes");
+}
+
+#[test]
+fn borrowed_view() {
+	let bytes = [1u8, 2, 3];
+	let view = <&DataView>::deserialize(BorrowedBytesDeserializer::<Error>::new(&bytes)).unwrap();
+	assert_eq!(view.as_ref().as_ptr(), bytes.as_ptr());
+	assert_ser_tokens(view, &[Token::Bytes(&[1, 2, 3])]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned_view() {
+	let buf = dataview::DataViewBuf::from(vec![1u8, 2, 3]);
+	assert_tokens(&buf, &[Token::Bytes(&[1, 2, 3])]);
+	assert_de_tokens(&buf, &[Token::ByteBuf(&[1, 2, 3])]);
+	assert_de_tokens(&buf, &[Token::Seq { len: None }, Token::U8(1), Token::U8(2), Token::U8(3), Token::SeqEnd]);
+}